@@ -7,9 +7,20 @@ set -eux
 #
 # * By default, `char` is converted to `::std::os::raw::c_char`. However, to
 #   preserve no_std, it is truncated to `c_char` and gets taken from `libc`.
+#
+# * Integers are declared as `lapack_int`, which is 32 or 64 bits wide depending
+#   on the `ilp64` feature. With the `ilp64-suffix` feature, the `_64_`-suffixed
+#   symbols of the ILP64 interface are linked instead.
+#
+# * The deprecated routines that are part of `lapack.h` are marked as such and
+#   point to their replacements.
 bindgen --whitelist-function='^.*_$' --use-core bin/wrapper.h \
   | sed -e 's/::std::os::raw:://g' \
   | sed -e '/__darwin_size_t/d' \
+  | sed -E -e 's/\bc_int\b/lapack_int/g' \
+  | sed -E -e 's/^( *)(pub fn ([cdsz])geqpf_\()/\1#[deprecated(note = "use \3geqp3_ instead")]\n\1\2/' \
+  | sed -E -e 's/^( *)(pub fn ([cdsz])(ggsvd|ggsvp)_\()/\1#[deprecated(note = "use \3\43_ instead")]\n\1\2/' \
+  | sed -E -e 's/^( *)pub fn ([a-z0-9_]+)_\(/\1#[cfg_attr(feature = "ilp64-suffix", link_name = "\2_64_")]\n\1pub fn \2_(/' \
   > src/lapack.rs
 
 rustfmt src/lapack.rs
@@ -0,0 +1,437 @@
+// Deprecated routines that LAPACK builds only on request (`BUILD_DEPRECATED`).
+// They are available with the `deprecated-routines` feature.
+
+extern "C" {
+    #[deprecated(note = "use cgges_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgegs_64_")]
+    pub fn cgegs_(
+        jobvsl: *const c_char,
+        jobvsr: *const c_char,
+        n: *const lapack_int,
+        A: *mut __BindgenComplex<f32>,
+        lda: *const lapack_int,
+        B: *mut __BindgenComplex<f32>,
+        ldb: *const lapack_int,
+        alpha: *mut __BindgenComplex<f32>,
+        beta: *mut __BindgenComplex<f32>,
+        VSL: *mut __BindgenComplex<f32>,
+        ldvsl: *const lapack_int,
+        VSR: *mut __BindgenComplex<f32>,
+        ldvsr: *const lapack_int,
+        work: *mut __BindgenComplex<f32>,
+        lwork: *const lapack_int,
+        rwork: *mut f32,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[deprecated(note = "use dgges_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgegs_64_")]
+    pub fn dgegs_(
+        jobvsl: *const c_char,
+        jobvsr: *const c_char,
+        n: *const lapack_int,
+        A: *mut f64,
+        lda: *const lapack_int,
+        B: *mut f64,
+        ldb: *const lapack_int,
+        alphar: *mut f64,
+        alphai: *mut f64,
+        beta: *mut f64,
+        VSL: *mut f64,
+        ldvsl: *const lapack_int,
+        VSR: *mut f64,
+        ldvsr: *const lapack_int,
+        work: *mut f64,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[deprecated(note = "use sgges_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgegs_64_")]
+    pub fn sgegs_(
+        jobvsl: *const c_char,
+        jobvsr: *const c_char,
+        n: *const lapack_int,
+        A: *mut f32,
+        lda: *const lapack_int,
+        B: *mut f32,
+        ldb: *const lapack_int,
+        alphar: *mut f32,
+        alphai: *mut f32,
+        beta: *mut f32,
+        VSL: *mut f32,
+        ldvsl: *const lapack_int,
+        VSR: *mut f32,
+        ldvsr: *const lapack_int,
+        work: *mut f32,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[deprecated(note = "use zgges_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgegs_64_")]
+    pub fn zgegs_(
+        jobvsl: *const c_char,
+        jobvsr: *const c_char,
+        n: *const lapack_int,
+        A: *mut __BindgenComplex<f64>,
+        lda: *const lapack_int,
+        B: *mut __BindgenComplex<f64>,
+        ldb: *const lapack_int,
+        alpha: *mut __BindgenComplex<f64>,
+        beta: *mut __BindgenComplex<f64>,
+        VSL: *mut __BindgenComplex<f64>,
+        ldvsl: *const lapack_int,
+        VSR: *mut __BindgenComplex<f64>,
+        ldvsr: *const lapack_int,
+        work: *mut __BindgenComplex<f64>,
+        lwork: *const lapack_int,
+        rwork: *mut f64,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[deprecated(note = "use cggev_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgegv_64_")]
+    pub fn cgegv_(
+        jobvl: *const c_char,
+        jobvr: *const c_char,
+        n: *const lapack_int,
+        A: *mut __BindgenComplex<f32>,
+        lda: *const lapack_int,
+        B: *mut __BindgenComplex<f32>,
+        ldb: *const lapack_int,
+        alpha: *mut __BindgenComplex<f32>,
+        beta: *mut __BindgenComplex<f32>,
+        VL: *mut __BindgenComplex<f32>,
+        ldvl: *const lapack_int,
+        VR: *mut __BindgenComplex<f32>,
+        ldvr: *const lapack_int,
+        work: *mut __BindgenComplex<f32>,
+        lwork: *const lapack_int,
+        rwork: *mut f32,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[deprecated(note = "use dggev_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgegv_64_")]
+    pub fn dgegv_(
+        jobvl: *const c_char,
+        jobvr: *const c_char,
+        n: *const lapack_int,
+        A: *mut f64,
+        lda: *const lapack_int,
+        B: *mut f64,
+        ldb: *const lapack_int,
+        alphar: *mut f64,
+        alphai: *mut f64,
+        beta: *mut f64,
+        VL: *mut f64,
+        ldvl: *const lapack_int,
+        VR: *mut f64,
+        ldvr: *const lapack_int,
+        work: *mut f64,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[deprecated(note = "use sggev_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgegv_64_")]
+    pub fn sgegv_(
+        jobvl: *const c_char,
+        jobvr: *const c_char,
+        n: *const lapack_int,
+        A: *mut f32,
+        lda: *const lapack_int,
+        B: *mut f32,
+        ldb: *const lapack_int,
+        alphar: *mut f32,
+        alphai: *mut f32,
+        beta: *mut f32,
+        VL: *mut f32,
+        ldvl: *const lapack_int,
+        VR: *mut f32,
+        ldvr: *const lapack_int,
+        work: *mut f32,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[deprecated(note = "use zggev_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgegv_64_")]
+    pub fn zgegv_(
+        jobvl: *const c_char,
+        jobvr: *const c_char,
+        n: *const lapack_int,
+        A: *mut __BindgenComplex<f64>,
+        lda: *const lapack_int,
+        B: *mut __BindgenComplex<f64>,
+        ldb: *const lapack_int,
+        alpha: *mut __BindgenComplex<f64>,
+        beta: *mut __BindgenComplex<f64>,
+        VL: *mut __BindgenComplex<f64>,
+        ldvl: *const lapack_int,
+        VR: *mut __BindgenComplex<f64>,
+        ldvr: *const lapack_int,
+        work: *mut __BindgenComplex<f64>,
+        lwork: *const lapack_int,
+        rwork: *mut f64,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[deprecated(note = "use cgelsy_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgelsx_64_")]
+    pub fn cgelsx_(
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
+        A: *mut __BindgenComplex<f32>,
+        lda: *const lapack_int,
+        B: *mut __BindgenComplex<f32>,
+        ldb: *const lapack_int,
+        JPVT: *mut lapack_int,
+        rcond: *const f32,
+        rank: *mut lapack_int,
+        work: *mut __BindgenComplex<f32>,
+        rwork: *mut f32,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[deprecated(note = "use dgelsy_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgelsx_64_")]
+    pub fn dgelsx_(
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
+        A: *mut f64,
+        lda: *const lapack_int,
+        B: *mut f64,
+        ldb: *const lapack_int,
+        JPVT: *mut lapack_int,
+        rcond: *const f64,
+        rank: *mut lapack_int,
+        work: *mut f64,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[deprecated(note = "use sgelsy_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgelsx_64_")]
+    pub fn sgelsx_(
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
+        A: *mut f32,
+        lda: *const lapack_int,
+        B: *mut f32,
+        ldb: *const lapack_int,
+        JPVT: *mut lapack_int,
+        rcond: *const f32,
+        rank: *mut lapack_int,
+        work: *mut f32,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[deprecated(note = "use zgelsy_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgelsx_64_")]
+    pub fn zgelsx_(
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
+        A: *mut __BindgenComplex<f64>,
+        lda: *const lapack_int,
+        B: *mut __BindgenComplex<f64>,
+        ldb: *const lapack_int,
+        JPVT: *mut lapack_int,
+        rcond: *const f64,
+        rank: *mut lapack_int,
+        work: *mut __BindgenComplex<f64>,
+        rwork: *mut f64,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[deprecated(note = "use clahr2_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clahrd_64_")]
+    pub fn clahrd_(
+        n: *const lapack_int,
+        k: *const lapack_int,
+        nb: *const lapack_int,
+        A: *mut __BindgenComplex<f32>,
+        lda: *const lapack_int,
+        tau: *mut __BindgenComplex<f32>,
+        T: *mut __BindgenComplex<f32>,
+        ldt: *const lapack_int,
+        Y: *mut __BindgenComplex<f32>,
+        ldy: *const lapack_int,
+    );
+}
+extern "C" {
+    #[deprecated(note = "use dlahr2_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlahrd_64_")]
+    pub fn dlahrd_(
+        n: *const lapack_int,
+        k: *const lapack_int,
+        nb: *const lapack_int,
+        A: *mut f64,
+        lda: *const lapack_int,
+        tau: *mut f64,
+        T: *mut f64,
+        ldt: *const lapack_int,
+        Y: *mut f64,
+        ldy: *const lapack_int,
+    );
+}
+extern "C" {
+    #[deprecated(note = "use slahr2_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slahrd_64_")]
+    pub fn slahrd_(
+        n: *const lapack_int,
+        k: *const lapack_int,
+        nb: *const lapack_int,
+        A: *mut f32,
+        lda: *const lapack_int,
+        tau: *mut f32,
+        T: *mut f32,
+        ldt: *const lapack_int,
+        Y: *mut f32,
+        ldy: *const lapack_int,
+    );
+}
+extern "C" {
+    #[deprecated(note = "use zlahr2_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlahrd_64_")]
+    pub fn zlahrd_(
+        n: *const lapack_int,
+        k: *const lapack_int,
+        nb: *const lapack_int,
+        A: *mut __BindgenComplex<f64>,
+        lda: *const lapack_int,
+        tau: *mut __BindgenComplex<f64>,
+        T: *mut __BindgenComplex<f64>,
+        ldt: *const lapack_int,
+        Y: *mut __BindgenComplex<f64>,
+        ldy: *const lapack_int,
+    );
+}
+extern "C" {
+    #[deprecated(note = "use cunmrz_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clatzm_64_")]
+    pub fn clatzm_(
+        side: *const c_char,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        V: *const __BindgenComplex<f32>,
+        incv: *const lapack_int,
+        tau: *const __BindgenComplex<f32>,
+        C1: *mut __BindgenComplex<f32>,
+        C2: *mut __BindgenComplex<f32>,
+        ldc: *const lapack_int,
+        work: *mut __BindgenComplex<f32>,
+    );
+}
+extern "C" {
+    #[deprecated(note = "use dormrz_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlatzm_64_")]
+    pub fn dlatzm_(
+        side: *const c_char,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        V: *const f64,
+        incv: *const lapack_int,
+        tau: *const f64,
+        C1: *mut f64,
+        C2: *mut f64,
+        ldc: *const lapack_int,
+        work: *mut f64,
+    );
+}
+extern "C" {
+    #[deprecated(note = "use sormrz_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slatzm_64_")]
+    pub fn slatzm_(
+        side: *const c_char,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        V: *const f32,
+        incv: *const lapack_int,
+        tau: *const f32,
+        C1: *mut f32,
+        C2: *mut f32,
+        ldc: *const lapack_int,
+        work: *mut f32,
+    );
+}
+extern "C" {
+    #[deprecated(note = "use zunmrz_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlatzm_64_")]
+    pub fn zlatzm_(
+        side: *const c_char,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        V: *const __BindgenComplex<f64>,
+        incv: *const lapack_int,
+        tau: *const __BindgenComplex<f64>,
+        C1: *mut __BindgenComplex<f64>,
+        C2: *mut __BindgenComplex<f64>,
+        ldc: *const lapack_int,
+        work: *mut __BindgenComplex<f64>,
+    );
+}
+extern "C" {
+    #[deprecated(note = "use ctzrzf_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctzrqf_64_")]
+    pub fn ctzrqf_(
+        m: *const lapack_int,
+        n: *const lapack_int,
+        A: *mut __BindgenComplex<f32>,
+        lda: *const lapack_int,
+        tau: *mut __BindgenComplex<f32>,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[deprecated(note = "use dtzrzf_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtzrqf_64_")]
+    pub fn dtzrqf_(
+        m: *const lapack_int,
+        n: *const lapack_int,
+        A: *mut f64,
+        lda: *const lapack_int,
+        tau: *mut f64,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[deprecated(note = "use stzrzf_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stzrqf_64_")]
+    pub fn stzrqf_(
+        m: *const lapack_int,
+        n: *const lapack_int,
+        A: *mut f32,
+        lda: *const lapack_int,
+        tau: *mut f32,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[deprecated(note = "use ztzrzf_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztzrqf_64_")]
+    pub fn ztzrqf_(
+        m: *const lapack_int,
+        n: *const lapack_int,
+        A: *mut __BindgenComplex<f64>,
+        lda: *const lapack_int,
+        tau: *mut __BindgenComplex<f64>,
+        info: *mut lapack_int,
+    );
+}
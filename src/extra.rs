@@ -0,0 +1,989 @@
+// Routines that are not declared in `lapack.h`, and hence not covered by
+// `bin/generate.sh`, are declared here by hand following the same conventions.
+// The hidden lengths of `CHARACTER*(*)` arguments are passed explicitly as
+// trailing `size_t` parameters. `cladiv` and `zladiv` are left out since they
+// return `COMPLEX`, whose return convention depends on the Fortran compiler
+// (`-ff2c`); `lapack.h` declares no such functions either.
+
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgedmd_64_")]
+    pub fn cgedmd_(
+        jobs: *const c_char,
+        jobz: *const c_char,
+        jobr: *const c_char,
+        jobf: *const c_char,
+        whtsvd: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        x: *mut __BindgenComplex<f32>,
+        ldx: *const lapack_int,
+        y: *mut __BindgenComplex<f32>,
+        ldy: *const lapack_int,
+        nrnk: *const lapack_int,
+        tol: *const f32,
+        k: *mut lapack_int,
+        eigs: *mut __BindgenComplex<f32>,
+        z: *mut __BindgenComplex<f32>,
+        ldz: *const lapack_int,
+        res: *mut f32,
+        b: *mut __BindgenComplex<f32>,
+        ldb: *const lapack_int,
+        w: *mut __BindgenComplex<f32>,
+        ldw: *const lapack_int,
+        s: *mut __BindgenComplex<f32>,
+        lds: *const lapack_int,
+        zwork: *mut __BindgenComplex<f32>,
+        lzwork: *const lapack_int,
+        rwork: *mut f32,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgedmd_64_")]
+    pub fn dgedmd_(
+        jobs: *const c_char,
+        jobz: *const c_char,
+        jobr: *const c_char,
+        jobf: *const c_char,
+        whtsvd: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        x: *mut f64,
+        ldx: *const lapack_int,
+        y: *mut f64,
+        ldy: *const lapack_int,
+        nrnk: *const lapack_int,
+        tol: *const f64,
+        k: *mut lapack_int,
+        reig: *mut f64,
+        imeig: *mut f64,
+        z: *mut f64,
+        ldz: *const lapack_int,
+        res: *mut f64,
+        b: *mut f64,
+        ldb: *const lapack_int,
+        w: *mut f64,
+        ldw: *const lapack_int,
+        s: *mut f64,
+        lds: *const lapack_int,
+        work: *mut f64,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgedmd_64_")]
+    pub fn sgedmd_(
+        jobs: *const c_char,
+        jobz: *const c_char,
+        jobr: *const c_char,
+        jobf: *const c_char,
+        whtsvd: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        x: *mut f32,
+        ldx: *const lapack_int,
+        y: *mut f32,
+        ldy: *const lapack_int,
+        nrnk: *const lapack_int,
+        tol: *const f32,
+        k: *mut lapack_int,
+        reig: *mut f32,
+        imeig: *mut f32,
+        z: *mut f32,
+        ldz: *const lapack_int,
+        res: *mut f32,
+        b: *mut f32,
+        ldb: *const lapack_int,
+        w: *mut f32,
+        ldw: *const lapack_int,
+        s: *mut f32,
+        lds: *const lapack_int,
+        work: *mut f32,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgedmd_64_")]
+    pub fn zgedmd_(
+        jobs: *const c_char,
+        jobz: *const c_char,
+        jobr: *const c_char,
+        jobf: *const c_char,
+        whtsvd: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        x: *mut __BindgenComplex<f64>,
+        ldx: *const lapack_int,
+        y: *mut __BindgenComplex<f64>,
+        ldy: *const lapack_int,
+        nrnk: *const lapack_int,
+        tol: *const f64,
+        k: *mut lapack_int,
+        eigs: *mut __BindgenComplex<f64>,
+        z: *mut __BindgenComplex<f64>,
+        ldz: *const lapack_int,
+        res: *mut f64,
+        b: *mut __BindgenComplex<f64>,
+        ldb: *const lapack_int,
+        w: *mut __BindgenComplex<f64>,
+        ldw: *const lapack_int,
+        s: *mut __BindgenComplex<f64>,
+        lds: *const lapack_int,
+        zwork: *mut __BindgenComplex<f64>,
+        lzwork: *const lapack_int,
+        rwork: *mut f64,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgedmdq_64_")]
+    pub fn cgedmdq_(
+        jobs: *const c_char,
+        jobz: *const c_char,
+        jobr: *const c_char,
+        jobq: *const c_char,
+        jobt: *const c_char,
+        jobf: *const c_char,
+        whtsvd: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        f: *mut __BindgenComplex<f32>,
+        ldf: *const lapack_int,
+        x: *mut __BindgenComplex<f32>,
+        ldx: *const lapack_int,
+        y: *mut __BindgenComplex<f32>,
+        ldy: *const lapack_int,
+        nrnk: *const lapack_int,
+        tol: *const f32,
+        k: *mut lapack_int,
+        eigs: *mut __BindgenComplex<f32>,
+        z: *mut __BindgenComplex<f32>,
+        ldz: *const lapack_int,
+        res: *mut f32,
+        b: *mut __BindgenComplex<f32>,
+        ldb: *const lapack_int,
+        v: *mut __BindgenComplex<f32>,
+        ldv: *const lapack_int,
+        s: *mut __BindgenComplex<f32>,
+        lds: *const lapack_int,
+        zwork: *mut __BindgenComplex<f32>,
+        lzwork: *const lapack_int,
+        work: *mut f32,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgedmdq_64_")]
+    pub fn dgedmdq_(
+        jobs: *const c_char,
+        jobz: *const c_char,
+        jobr: *const c_char,
+        jobq: *const c_char,
+        jobt: *const c_char,
+        jobf: *const c_char,
+        whtsvd: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        f: *mut f64,
+        ldf: *const lapack_int,
+        x: *mut f64,
+        ldx: *const lapack_int,
+        y: *mut f64,
+        ldy: *const lapack_int,
+        nrnk: *const lapack_int,
+        tol: *const f64,
+        k: *mut lapack_int,
+        reig: *mut f64,
+        imeig: *mut f64,
+        z: *mut f64,
+        ldz: *const lapack_int,
+        res: *mut f64,
+        b: *mut f64,
+        ldb: *const lapack_int,
+        v: *mut f64,
+        ldv: *const lapack_int,
+        s: *mut f64,
+        lds: *const lapack_int,
+        work: *mut f64,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgedmdq_64_")]
+    pub fn sgedmdq_(
+        jobs: *const c_char,
+        jobz: *const c_char,
+        jobr: *const c_char,
+        jobq: *const c_char,
+        jobt: *const c_char,
+        jobf: *const c_char,
+        whtsvd: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        f: *mut f32,
+        ldf: *const lapack_int,
+        x: *mut f32,
+        ldx: *const lapack_int,
+        y: *mut f32,
+        ldy: *const lapack_int,
+        nrnk: *const lapack_int,
+        tol: *const f32,
+        k: *mut lapack_int,
+        reig: *mut f32,
+        imeig: *mut f32,
+        z: *mut f32,
+        ldz: *const lapack_int,
+        res: *mut f32,
+        b: *mut f32,
+        ldb: *const lapack_int,
+        v: *mut f32,
+        ldv: *const lapack_int,
+        s: *mut f32,
+        lds: *const lapack_int,
+        work: *mut f32,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgedmdq_64_")]
+    pub fn zgedmdq_(
+        jobs: *const c_char,
+        jobz: *const c_char,
+        jobr: *const c_char,
+        jobq: *const c_char,
+        jobt: *const c_char,
+        jobf: *const c_char,
+        whtsvd: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        f: *mut __BindgenComplex<f64>,
+        ldf: *const lapack_int,
+        x: *mut __BindgenComplex<f64>,
+        ldx: *const lapack_int,
+        y: *mut __BindgenComplex<f64>,
+        ldy: *const lapack_int,
+        nrnk: *const lapack_int,
+        tol: *const f64,
+        k: *mut lapack_int,
+        eigs: *mut __BindgenComplex<f64>,
+        z: *mut __BindgenComplex<f64>,
+        ldz: *const lapack_int,
+        res: *mut f64,
+        b: *mut __BindgenComplex<f64>,
+        ldb: *const lapack_int,
+        v: *mut __BindgenComplex<f64>,
+        ldv: *const lapack_int,
+        s: *mut __BindgenComplex<f64>,
+        lds: *const lapack_int,
+        zwork: *mut __BindgenComplex<f64>,
+        lzwork: *const lapack_int,
+        work: *mut f64,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "checon_rook_64_")]
+    pub fn checon_rook_(
+        uplo: *const c_char,
+        n: *const lapack_int,
+        A: *const __BindgenComplex<f32>,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
+        anorm: *const f32,
+        rcond: *mut f32,
+        work: *mut __BindgenComplex<f32>,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhecon_rook_64_")]
+    pub fn zhecon_rook_(
+        uplo: *const c_char,
+        n: *const lapack_int,
+        A: *const __BindgenComplex<f64>,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
+        anorm: *const f64,
+        rcond: *mut f64,
+        work: *mut __BindgenComplex<f64>,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chetrd_hb2st_64_")]
+    pub fn chetrd_hb2st_(
+        stage1: *const c_char,
+        vect: *const c_char,
+        uplo: *const c_char,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        AB: *mut __BindgenComplex<f32>,
+        ldab: *const lapack_int,
+        D: *mut f32,
+        E: *mut f32,
+        HOUS: *mut __BindgenComplex<f32>,
+        lhous: *const lapack_int,
+        work: *mut __BindgenComplex<f32>,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhetrd_hb2st_64_")]
+    pub fn zhetrd_hb2st_(
+        stage1: *const c_char,
+        vect: *const c_char,
+        uplo: *const c_char,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        AB: *mut __BindgenComplex<f64>,
+        ldab: *const lapack_int,
+        D: *mut f64,
+        E: *mut f64,
+        HOUS: *mut __BindgenComplex<f64>,
+        lhous: *const lapack_int,
+        work: *mut __BindgenComplex<f64>,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chetrd_he2hb_64_")]
+    pub fn chetrd_he2hb_(
+        uplo: *const c_char,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        A: *mut __BindgenComplex<f32>,
+        lda: *const lapack_int,
+        AB: *mut __BindgenComplex<f32>,
+        ldab: *const lapack_int,
+        tau: *mut __BindgenComplex<f32>,
+        work: *mut __BindgenComplex<f32>,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhetrd_he2hb_64_")]
+    pub fn zhetrd_he2hb_(
+        uplo: *const c_char,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        A: *mut __BindgenComplex<f64>,
+        lda: *const lapack_int,
+        AB: *mut __BindgenComplex<f64>,
+        ldab: *const lapack_int,
+        tau: *mut __BindgenComplex<f64>,
+        work: *mut __BindgenComplex<f64>,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chetri_3x_64_")]
+    pub fn chetri_3x_(
+        uplo: *const c_char,
+        n: *const lapack_int,
+        A: *mut __BindgenComplex<f32>,
+        lda: *const lapack_int,
+        E: *const __BindgenComplex<f32>,
+        ipiv: *const lapack_int,
+        work: *mut __BindgenComplex<f32>,
+        nb: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhetri_3x_64_")]
+    pub fn zhetri_3x_(
+        uplo: *const c_char,
+        n: *const lapack_int,
+        A: *mut __BindgenComplex<f64>,
+        lda: *const lapack_int,
+        E: *const __BindgenComplex<f64>,
+        ipiv: *const lapack_int,
+        work: *mut __BindgenComplex<f64>,
+        nb: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chetri_rook_64_")]
+    pub fn chetri_rook_(
+        uplo: *const c_char,
+        n: *const lapack_int,
+        A: *mut __BindgenComplex<f32>,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
+        work: *mut __BindgenComplex<f32>,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhetri_rook_64_")]
+    pub fn zhetri_rook_(
+        uplo: *const c_char,
+        n: *const lapack_int,
+        A: *mut __BindgenComplex<f64>,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
+        work: *mut __BindgenComplex<f64>,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ilaenv2stage_64_")]
+    pub fn ilaenv2stage_(
+        ispec: *const lapack_int,
+        name: *const c_char,
+        opts: *const c_char,
+        n1: *const lapack_int,
+        n2: *const lapack_int,
+        n3: *const lapack_int,
+        n4: *const lapack_int,
+        lname: size_t,
+        lopts: size_t,
+    ) -> lapack_int;
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ilaenv_64_")]
+    pub fn ilaenv_(
+        ispec: *const lapack_int,
+        name: *const c_char,
+        opts: *const c_char,
+        n1: *const lapack_int,
+        n2: *const lapack_int,
+        n3: *const lapack_int,
+        n4: *const lapack_int,
+        lname: size_t,
+        lopts: size_t,
+    ) -> lapack_int;
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "iparmq_64_")]
+    pub fn iparmq_(
+        ispec: *const lapack_int,
+        name: *const c_char,
+        opts: *const c_char,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
+        lwork: *const lapack_int,
+        lname: size_t,
+        lopts: size_t,
+    ) -> lapack_int;
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "disnan_64_")]
+    pub fn disnan_(din: *const f64) -> lapack_logical;
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sisnan_64_")]
+    pub fn sisnan_(din: *const f32) -> lapack_logical;
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dladiv_64_")]
+    pub fn dladiv_(
+        a: *const f64,
+        b: *const f64,
+        c: *const f64,
+        d: *const f64,
+        p: *mut f64,
+        q: *mut f64,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sladiv_64_")]
+    pub fn sladiv_(
+        a: *const f32,
+        b: *const f32,
+        c: *const f32,
+        d: *const f32,
+        p: *mut f32,
+        q: *mut f32,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlaisnan_64_")]
+    pub fn dlaisnan_(din1: *const f64, din2: *const f64) -> lapack_logical;
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slaisnan_64_")]
+    pub fn slaisnan_(din1: *const f32, din2: *const f32) -> lapack_logical;
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clartg_64_")]
+    pub fn clartg_(
+        f: *const __BindgenComplex<f32>,
+        g: *const __BindgenComplex<f32>,
+        cs: *mut f32,
+        sn: *mut __BindgenComplex<f32>,
+        r: *mut __BindgenComplex<f32>,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlartg_64_")]
+    pub fn dlartg_(f: *const f64, g: *const f64, cs: *mut f64, sn: *mut f64, r: *mut f64);
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slartg_64_")]
+    pub fn slartg_(f: *const f32, g: *const f32, cs: *mut f32, sn: *mut f32, r: *mut f32);
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlartg_64_")]
+    pub fn zlartg_(
+        f: *const __BindgenComplex<f64>,
+        g: *const __BindgenComplex<f64>,
+        cs: *mut f64,
+        sn: *mut __BindgenComplex<f64>,
+        r: *mut __BindgenComplex<f64>,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csycon_rook_64_")]
+    pub fn csycon_rook_(
+        uplo: *const c_char,
+        n: *const lapack_int,
+        A: *const __BindgenComplex<f32>,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
+        anorm: *const f32,
+        rcond: *mut f32,
+        work: *mut __BindgenComplex<f32>,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsycon_rook_64_")]
+    pub fn dsycon_rook_(
+        uplo: *const c_char,
+        n: *const lapack_int,
+        A: *const f64,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
+        anorm: *const f64,
+        rcond: *mut f64,
+        work: *mut f64,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssycon_rook_64_")]
+    pub fn ssycon_rook_(
+        uplo: *const c_char,
+        n: *const lapack_int,
+        A: *const f32,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
+        anorm: *const f32,
+        rcond: *mut f32,
+        work: *mut f32,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsycon_rook_64_")]
+    pub fn zsycon_rook_(
+        uplo: *const c_char,
+        n: *const lapack_int,
+        A: *const __BindgenComplex<f64>,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
+        anorm: *const f64,
+        rcond: *mut f64,
+        work: *mut __BindgenComplex<f64>,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csyconvf_64_")]
+    pub fn csyconvf_(
+        uplo: *const c_char,
+        way: *const c_char,
+        n: *const lapack_int,
+        A: *mut __BindgenComplex<f32>,
+        lda: *const lapack_int,
+        E: *mut __BindgenComplex<f32>,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsyconvf_64_")]
+    pub fn dsyconvf_(
+        uplo: *const c_char,
+        way: *const c_char,
+        n: *const lapack_int,
+        A: *mut f64,
+        lda: *const lapack_int,
+        E: *mut f64,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssyconvf_64_")]
+    pub fn ssyconvf_(
+        uplo: *const c_char,
+        way: *const c_char,
+        n: *const lapack_int,
+        A: *mut f32,
+        lda: *const lapack_int,
+        E: *mut f32,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsyconvf_64_")]
+    pub fn zsyconvf_(
+        uplo: *const c_char,
+        way: *const c_char,
+        n: *const lapack_int,
+        A: *mut __BindgenComplex<f64>,
+        lda: *const lapack_int,
+        E: *mut __BindgenComplex<f64>,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csyconvf_rook_64_")]
+    pub fn csyconvf_rook_(
+        uplo: *const c_char,
+        way: *const c_char,
+        n: *const lapack_int,
+        A: *mut __BindgenComplex<f32>,
+        lda: *const lapack_int,
+        E: *mut __BindgenComplex<f32>,
+        ipiv: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsyconvf_rook_64_")]
+    pub fn dsyconvf_rook_(
+        uplo: *const c_char,
+        way: *const c_char,
+        n: *const lapack_int,
+        A: *mut f64,
+        lda: *const lapack_int,
+        E: *mut f64,
+        ipiv: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssyconvf_rook_64_")]
+    pub fn ssyconvf_rook_(
+        uplo: *const c_char,
+        way: *const c_char,
+        n: *const lapack_int,
+        A: *mut f32,
+        lda: *const lapack_int,
+        E: *mut f32,
+        ipiv: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsyconvf_rook_64_")]
+    pub fn zsyconvf_rook_(
+        uplo: *const c_char,
+        way: *const c_char,
+        n: *const lapack_int,
+        A: *mut __BindgenComplex<f64>,
+        lda: *const lapack_int,
+        E: *mut __BindgenComplex<f64>,
+        ipiv: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsytrd_sb2st_64_")]
+    pub fn dsytrd_sb2st_(
+        stage1: *const c_char,
+        vect: *const c_char,
+        uplo: *const c_char,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        AB: *mut f64,
+        ldab: *const lapack_int,
+        D: *mut f64,
+        E: *mut f64,
+        HOUS: *mut f64,
+        lhous: *const lapack_int,
+        work: *mut f64,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssytrd_sb2st_64_")]
+    pub fn ssytrd_sb2st_(
+        stage1: *const c_char,
+        vect: *const c_char,
+        uplo: *const c_char,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        AB: *mut f32,
+        ldab: *const lapack_int,
+        D: *mut f32,
+        E: *mut f32,
+        HOUS: *mut f32,
+        lhous: *const lapack_int,
+        work: *mut f32,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsytrd_sy2sb_64_")]
+    pub fn dsytrd_sy2sb_(
+        uplo: *const c_char,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        A: *mut f64,
+        lda: *const lapack_int,
+        AB: *mut f64,
+        ldab: *const lapack_int,
+        tau: *mut f64,
+        work: *mut f64,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssytrd_sy2sb_64_")]
+    pub fn ssytrd_sy2sb_(
+        uplo: *const c_char,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        A: *mut f32,
+        lda: *const lapack_int,
+        AB: *mut f32,
+        ldab: *const lapack_int,
+        tau: *mut f32,
+        work: *mut f32,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csytri_3x_64_")]
+    pub fn csytri_3x_(
+        uplo: *const c_char,
+        n: *const lapack_int,
+        A: *mut __BindgenComplex<f32>,
+        lda: *const lapack_int,
+        E: *const __BindgenComplex<f32>,
+        ipiv: *const lapack_int,
+        work: *mut __BindgenComplex<f32>,
+        nb: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsytri_3x_64_")]
+    pub fn dsytri_3x_(
+        uplo: *const c_char,
+        n: *const lapack_int,
+        A: *mut f64,
+        lda: *const lapack_int,
+        E: *const f64,
+        ipiv: *const lapack_int,
+        work: *mut f64,
+        nb: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssytri_3x_64_")]
+    pub fn ssytri_3x_(
+        uplo: *const c_char,
+        n: *const lapack_int,
+        A: *mut f32,
+        lda: *const lapack_int,
+        E: *const f32,
+        ipiv: *const lapack_int,
+        work: *mut f32,
+        nb: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsytri_3x_64_")]
+    pub fn zsytri_3x_(
+        uplo: *const c_char,
+        n: *const lapack_int,
+        A: *mut __BindgenComplex<f64>,
+        lda: *const lapack_int,
+        E: *const __BindgenComplex<f64>,
+        ipiv: *const lapack_int,
+        work: *mut __BindgenComplex<f64>,
+        nb: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csytri_rook_64_")]
+    pub fn csytri_rook_(
+        uplo: *const c_char,
+        n: *const lapack_int,
+        A: *mut __BindgenComplex<f32>,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
+        work: *mut __BindgenComplex<f32>,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsytri_rook_64_")]
+    pub fn dsytri_rook_(
+        uplo: *const c_char,
+        n: *const lapack_int,
+        A: *mut f64,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
+        work: *mut f64,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssytri_rook_64_")]
+    pub fn ssytri_rook_(
+        uplo: *const c_char,
+        n: *const lapack_int,
+        A: *mut f32,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
+        work: *mut f32,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsytri_rook_64_")]
+    pub fn zsytri_rook_(
+        uplo: *const c_char,
+        n: *const lapack_int,
+        A: *mut __BindgenComplex<f64>,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
+        work: *mut __BindgenComplex<f64>,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctrsyl3_64_")]
+    pub fn ctrsyl3_(
+        trana: *const c_char,
+        tranb: *const c_char,
+        isgn: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        A: *const __BindgenComplex<f32>,
+        lda: *const lapack_int,
+        B: *const __BindgenComplex<f32>,
+        ldb: *const lapack_int,
+        C: *mut __BindgenComplex<f32>,
+        ldc: *const lapack_int,
+        scale: *mut f32,
+        swork: *mut f32,
+        ldswork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtrsyl3_64_")]
+    pub fn dtrsyl3_(
+        trana: *const c_char,
+        tranb: *const c_char,
+        isgn: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        A: *const f64,
+        lda: *const lapack_int,
+        B: *const f64,
+        ldb: *const lapack_int,
+        C: *mut f64,
+        ldc: *const lapack_int,
+        scale: *mut f64,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        swork: *mut f64,
+        ldswork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "strsyl3_64_")]
+    pub fn strsyl3_(
+        trana: *const c_char,
+        tranb: *const c_char,
+        isgn: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        A: *const f32,
+        lda: *const lapack_int,
+        B: *const f32,
+        ldb: *const lapack_int,
+        C: *mut f32,
+        ldc: *const lapack_int,
+        scale: *mut f32,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        swork: *mut f32,
+        ldswork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztrsyl3_64_")]
+    pub fn ztrsyl3_(
+        trana: *const c_char,
+        tranb: *const c_char,
+        isgn: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        A: *const __BindgenComplex<f64>,
+        lda: *const lapack_int,
+        B: *const __BindgenComplex<f64>,
+        ldb: *const lapack_int,
+        C: *mut __BindgenComplex<f64>,
+        ldc: *const lapack_int,
+        scale: *mut f64,
+        swork: *mut f64,
+        ldswork: *const lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "xerbla_64_")]
+    pub fn xerbla_(srname: *const c_char, info: *const lapack_int, lsrname: size_t);
+}
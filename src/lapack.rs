@@ -8,54 +8,57 @@ pub struct __BindgenComplex<T> {
 }
 pub type lapack_float_return = f32;
 pub type LAPACK_S_SELECT2 =
-    ::core::option::Option<unsafe extern "C" fn(arg1: *const f32, arg2: *const f32) -> c_int>;
+    ::core::option::Option<unsafe extern "C" fn(arg1: *const f32, arg2: *const f32) -> lapack_int>;
 pub type LAPACK_S_SELECT3 = ::core::option::Option<
-    unsafe extern "C" fn(arg1: *const f32, arg2: *const f32, arg3: *const f32) -> c_int,
+    unsafe extern "C" fn(arg1: *const f32, arg2: *const f32, arg3: *const f32) -> lapack_int,
 >;
 pub type LAPACK_D_SELECT2 =
-    ::core::option::Option<unsafe extern "C" fn(arg1: *const f64, arg2: *const f64) -> c_int>;
+    ::core::option::Option<unsafe extern "C" fn(arg1: *const f64, arg2: *const f64) -> lapack_int>;
 pub type LAPACK_D_SELECT3 = ::core::option::Option<
-    unsafe extern "C" fn(arg1: *const f64, arg2: *const f64, arg3: *const f64) -> c_int,
+    unsafe extern "C" fn(arg1: *const f64, arg2: *const f64, arg3: *const f64) -> lapack_int,
 >;
 pub type LAPACK_C_SELECT1 =
-    ::core::option::Option<unsafe extern "C" fn(arg1: *const __BindgenComplex<f32>) -> c_int>;
+    ::core::option::Option<unsafe extern "C" fn(arg1: *const __BindgenComplex<f32>) -> lapack_int>;
 pub type LAPACK_C_SELECT2 = ::core::option::Option<
     unsafe extern "C" fn(
         arg1: *const __BindgenComplex<f32>,
         arg2: *const __BindgenComplex<f32>,
-    ) -> c_int,
+    ) -> lapack_int,
 >;
 pub type LAPACK_Z_SELECT1 =
-    ::core::option::Option<unsafe extern "C" fn(arg1: *const __BindgenComplex<f64>) -> c_int>;
+    ::core::option::Option<unsafe extern "C" fn(arg1: *const __BindgenComplex<f64>) -> lapack_int>;
 pub type LAPACK_Z_SELECT2 = ::core::option::Option<
     unsafe extern "C" fn(
         arg1: *const __BindgenComplex<f64>,
         arg2: *const __BindgenComplex<f64>,
-    ) -> c_int,
+    ) -> lapack_int,
 >;
 extern "C" {
-    pub fn lsame_(ca: *mut c_char, cb: *mut c_char, lca: c_int, lcb: c_int) -> c_int;
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "lsame_64_")]
+    pub fn lsame_(ca: *mut c_char, cb: *mut c_char, lca: lapack_int, lcb: lapack_int)
+        -> lapack_int;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cbbcsd_64_")]
     pub fn cbbcsd_(
         jobu1: *const c_char,
         jobu2: *const c_char,
         jobv1t: *const c_char,
         jobv2t: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        q: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        q: *const lapack_int,
         theta: *mut f32,
         phi: *mut f32,
         U1: *mut __BindgenComplex<f32>,
-        ldu1: *const c_int,
+        ldu1: *const lapack_int,
         U2: *mut __BindgenComplex<f32>,
-        ldu2: *const c_int,
+        ldu2: *const lapack_int,
         V1T: *mut __BindgenComplex<f32>,
-        ldv1t: *const c_int,
+        ldv1t: *const lapack_int,
         V2T: *mut __BindgenComplex<f32>,
-        ldv2t: *const c_int,
+        ldv2t: *const lapack_int,
         B11D: *mut f32,
         B11E: *mut f32,
         B12D: *mut f32,
@@ -65,30 +68,31 @@ extern "C" {
         B22D: *mut f32,
         B22E: *mut f32,
         rwork: *mut f32,
-        lrwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dbbcsd_64_")]
     pub fn dbbcsd_(
         jobu1: *const c_char,
         jobu2: *const c_char,
         jobv1t: *const c_char,
         jobv2t: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        q: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        q: *const lapack_int,
         theta: *mut f64,
         phi: *mut f64,
         U1: *mut f64,
-        ldu1: *const c_int,
+        ldu1: *const lapack_int,
         U2: *mut f64,
-        ldu2: *const c_int,
+        ldu2: *const lapack_int,
         V1T: *mut f64,
-        ldv1t: *const c_int,
+        ldv1t: *const lapack_int,
         V2T: *mut f64,
-        ldv2t: *const c_int,
+        ldv2t: *const lapack_int,
         B11D: *mut f64,
         B11E: *mut f64,
         B12D: *mut f64,
@@ -98,30 +102,31 @@ extern "C" {
         b22d: *mut f64,
         b22e: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sbbcsd_64_")]
     pub fn sbbcsd_(
         jobu1: *const c_char,
         jobu2: *const c_char,
         jobv1t: *const c_char,
         jobv2t: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        q: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        q: *const lapack_int,
         theta: *mut f32,
         phi: *mut f32,
         U1: *mut f32,
-        ldu1: *const c_int,
+        ldu1: *const lapack_int,
         U2: *mut f32,
-        ldu2: *const c_int,
+        ldu2: *const lapack_int,
         V1T: *mut f32,
-        ldv1t: *const c_int,
+        ldv1t: *const lapack_int,
         V2T: *mut f32,
-        ldv2t: *const c_int,
+        ldv2t: *const lapack_int,
         B11D: *mut f32,
         B11E: *mut f32,
         B12D: *mut f32,
@@ -131,30 +136,31 @@ extern "C" {
         B22D: *mut f32,
         B22E: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zbbcsd_64_")]
     pub fn zbbcsd_(
         jobu1: *const c_char,
         jobu2: *const c_char,
         jobv1t: *const c_char,
         jobv2t: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        q: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        q: *const lapack_int,
         theta: *mut f64,
         phi: *mut f64,
         U1: *mut __BindgenComplex<f64>,
-        ldu1: *const c_int,
+        ldu1: *const lapack_int,
         U2: *mut __BindgenComplex<f64>,
-        ldu2: *const c_int,
+        ldu2: *const lapack_int,
         V1T: *mut __BindgenComplex<f64>,
-        ldv1t: *const c_int,
+        ldv1t: *const lapack_int,
         V2T: *mut __BindgenComplex<f64>,
-        ldv2t: *const c_int,
+        ldv2t: *const lapack_int,
         B11D: *mut f64,
         B11E: *mut f64,
         B12D: *mut f64,
@@ -164,1817 +170,1916 @@ extern "C" {
         B22D: *mut f64,
         B22E: *mut f64,
         rwork: *mut f64,
-        lrwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dbdsdc_64_")]
     pub fn dbdsdc_(
         uplo: *const c_char,
         compq: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         U: *mut f64,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         VT: *mut f64,
-        ldvt: *const c_int,
+        ldvt: *const lapack_int,
         Q: *mut f64,
-        IQ: *mut c_int,
+        IQ: *mut lapack_int,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sbdsdc_64_")]
     pub fn sbdsdc_(
         uplo: *const c_char,
         compq: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         U: *mut f32,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         VT: *mut f32,
-        ldvt: *const c_int,
+        ldvt: *const lapack_int,
         Q: *mut f32,
-        IQ: *mut c_int,
+        IQ: *mut lapack_int,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cbdsqr_64_")]
     pub fn cbdsqr_(
         uplo: *const c_char,
-        n: *const c_int,
-        ncvt: *const c_int,
-        nru: *const c_int,
-        ncc: *const c_int,
+        n: *const lapack_int,
+        ncvt: *const lapack_int,
+        nru: *const lapack_int,
+        ncc: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         VT: *mut __BindgenComplex<f32>,
-        ldvt: *const c_int,
+        ldvt: *const lapack_int,
         U: *mut __BindgenComplex<f32>,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         C: *mut __BindgenComplex<f32>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dbdsqr_64_")]
     pub fn dbdsqr_(
         uplo: *const c_char,
-        n: *const c_int,
-        ncvt: *const c_int,
-        nru: *const c_int,
-        ncc: *const c_int,
+        n: *const lapack_int,
+        ncvt: *const lapack_int,
+        nru: *const lapack_int,
+        ncc: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         VT: *mut f64,
-        ldvt: *const c_int,
+        ldvt: *const lapack_int,
         U: *mut f64,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         C: *mut f64,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sbdsqr_64_")]
     pub fn sbdsqr_(
         uplo: *const c_char,
-        n: *const c_int,
-        ncvt: *const c_int,
-        nru: *const c_int,
-        ncc: *const c_int,
+        n: *const lapack_int,
+        ncvt: *const lapack_int,
+        nru: *const lapack_int,
+        ncc: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         VT: *mut f32,
-        ldvt: *const c_int,
+        ldvt: *const lapack_int,
         U: *mut f32,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         C: *mut f32,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zbdsqr_64_")]
     pub fn zbdsqr_(
         uplo: *const c_char,
-        n: *const c_int,
-        ncvt: *const c_int,
-        nru: *const c_int,
-        ncc: *const c_int,
+        n: *const lapack_int,
+        ncvt: *const lapack_int,
+        nru: *const lapack_int,
+        ncc: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         VT: *mut __BindgenComplex<f64>,
-        ldvt: *const c_int,
+        ldvt: *const lapack_int,
         U: *mut __BindgenComplex<f64>,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         C: *mut __BindgenComplex<f64>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dbdsvdx_64_")]
     pub fn dbdsvdx_(
         uplo: *const c_char,
         jobz: *const c_char,
         range: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *const f64,
         E: *const f64,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
-        ns: *mut c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
+        ns: *mut lapack_int,
         S: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sbdsvdx_64_")]
     pub fn sbdsvdx_(
         uplo: *const c_char,
         jobz: *const c_char,
         range: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *const f32,
         E: *const f32,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
-        ns: *mut c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
+        ns: *mut lapack_int,
         S: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ddisna_64_")]
     pub fn ddisna_(
         job: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         D: *const f64,
         SEP: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sdisna_64_")]
     pub fn sdisna_(
         job: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         D: *const f32,
         SEP: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgbbrd_64_")]
     pub fn cgbbrd_(
         vect: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        ncc: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        ncc: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *mut __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         Q: *mut __BindgenComplex<f32>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         PT: *mut __BindgenComplex<f32>,
-        ldpt: *const c_int,
+        ldpt: *const lapack_int,
         C: *mut __BindgenComplex<f32>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgbbrd_64_")]
     pub fn dgbbrd_(
         vect: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        ncc: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        ncc: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *mut f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         Q: *mut f64,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         PT: *mut f64,
-        ldpt: *const c_int,
+        ldpt: *const lapack_int,
         C: *mut f64,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgbbrd_64_")]
     pub fn sgbbrd_(
         vect: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        ncc: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        ncc: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *mut f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         Q: *mut f32,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         PT: *mut f32,
-        ldpt: *const c_int,
+        ldpt: *const lapack_int,
         C: *mut f32,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgbbrd_64_")]
     pub fn zgbbrd_(
         vect: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        ncc: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        ncc: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *mut __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         Q: *mut __BindgenComplex<f64>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         PT: *mut __BindgenComplex<f64>,
-        ldpt: *const c_int,
+        ldpt: *const lapack_int,
         C: *mut __BindgenComplex<f64>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgbcon_64_")]
     pub fn cgbcon_(
         norm: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *const __BindgenComplex<f32>,
-        ldab: *const c_int,
-        ipiv: *const c_int,
+        ldab: *const lapack_int,
+        ipiv: *const lapack_int,
         anorm: *const f32,
         rcond: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgbcon_64_")]
     pub fn dgbcon_(
         norm: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *const f64,
-        ldab: *const c_int,
-        ipiv: *const c_int,
+        ldab: *const lapack_int,
+        ipiv: *const lapack_int,
         anorm: *const f64,
         rcond: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgbcon_64_")]
     pub fn sgbcon_(
         norm: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *const f32,
-        ldab: *const c_int,
-        ipiv: *const c_int,
+        ldab: *const lapack_int,
+        ipiv: *const lapack_int,
         anorm: *const f32,
         rcond: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgbcon_64_")]
     pub fn zgbcon_(
         norm: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *const __BindgenComplex<f64>,
-        ldab: *const c_int,
-        ipiv: *const c_int,
+        ldab: *const lapack_int,
+        ipiv: *const lapack_int,
         anorm: *const f64,
         rcond: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgbequ_64_")]
     pub fn cgbequ_(
-        m: *const c_int,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *const __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         R: *mut f32,
         C: *mut f32,
         rowcnd: *mut f32,
         colcnd: *mut f32,
         amax: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgbequ_64_")]
     pub fn dgbequ_(
-        m: *const c_int,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *const f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         R: *mut f64,
         C: *mut f64,
         rowcnd: *mut f64,
         colcnd: *mut f64,
         amax: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgbequ_64_")]
     pub fn sgbequ_(
-        m: *const c_int,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *const f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         R: *mut f32,
         C: *mut f32,
         rowcnd: *mut f32,
         colcnd: *mut f32,
         amax: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgbequ_64_")]
     pub fn zgbequ_(
-        m: *const c_int,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *const __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         R: *mut f64,
         C: *mut f64,
         rowcnd: *mut f64,
         colcnd: *mut f64,
         amax: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgbequb_64_")]
     pub fn cgbequb_(
-        m: *const c_int,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *const __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         R: *mut f32,
         C: *mut f32,
         rowcnd: *mut f32,
         colcnd: *mut f32,
         amax: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgbequb_64_")]
     pub fn dgbequb_(
-        m: *const c_int,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *const f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         R: *mut f64,
         C: *mut f64,
         rowcnd: *mut f64,
         colcnd: *mut f64,
         amax: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgbequb_64_")]
     pub fn sgbequb_(
-        m: *const c_int,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *const f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         R: *mut f32,
         C: *mut f32,
         rowcnd: *mut f32,
         colcnd: *mut f32,
         amax: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgbequb_64_")]
     pub fn zgbequb_(
-        m: *const c_int,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *const __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         R: *mut f64,
         C: *mut f64,
         rowcnd: *mut f64,
         colcnd: *mut f64,
         amax: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgbrfs_64_")]
     pub fn cgbrfs_(
         trans: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *const __BindgenComplex<f32>,
-        ldafb: *const c_int,
-        ipiv: *const c_int,
+        ldafb: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgbrfs_64_")]
     pub fn dgbrfs_(
         trans: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *const f64,
-        ldafb: *const c_int,
-        ipiv: *const c_int,
+        ldafb: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgbrfs_64_")]
     pub fn sgbrfs_(
         trans: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *const f32,
-        ldafb: *const c_int,
-        ipiv: *const c_int,
+        ldafb: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgbrfs_64_")]
     pub fn zgbrfs_(
         trans: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *const __BindgenComplex<f64>,
-        ldafb: *const c_int,
-        ipiv: *const c_int,
+        ldafb: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgbrfsx_64_")]
     pub fn cgbrfsx_(
         trans: *const c_char,
         equed: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *const __BindgenComplex<f32>,
-        ldafb: *const c_int,
-        ipiv: *const c_int,
+        ldafb: *const lapack_int,
+        ipiv: *const lapack_int,
         R: *mut f32,
         C: *mut f32,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         berr: *mut f32,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f32,
         err_bnds_comp: *mut f32,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgbrfsx_64_")]
     pub fn dgbrfsx_(
         trans: *const c_char,
         equed: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *const f64,
-        ldafb: *const c_int,
-        ipiv: *const c_int,
+        ldafb: *const lapack_int,
+        ipiv: *const lapack_int,
         R: *mut f64,
         C: *mut f64,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         berr: *mut f64,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f64,
         err_bnds_comp: *mut f64,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgbrfsx_64_")]
     pub fn sgbrfsx_(
         trans: *const c_char,
         equed: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *const f32,
-        ldafb: *const c_int,
-        ipiv: *const c_int,
+        ldafb: *const lapack_int,
+        ipiv: *const lapack_int,
         R: *mut f32,
         C: *mut f32,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         berr: *mut f32,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f32,
         err_bnds_comp: *mut f32,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgbrfsx_64_")]
     pub fn zgbrfsx_(
         trans: *const c_char,
         equed: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *const __BindgenComplex<f64>,
-        ldafb: *const c_int,
-        ipiv: *const c_int,
+        ldafb: *const lapack_int,
+        ipiv: *const lapack_int,
         R: *mut f64,
         C: *mut f64,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         berr: *mut f64,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f64,
         err_bnds_comp: *mut f64,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgbsv_64_")]
     pub fn cgbsv_(
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *mut __BindgenComplex<f32>,
-        ldab: *const c_int,
-        ipiv: *mut c_int,
+        ldab: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgbsv_64_")]
     pub fn dgbsv_(
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *mut f64,
-        ldab: *const c_int,
-        ipiv: *mut c_int,
+        ldab: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgbsv_64_")]
     pub fn sgbsv_(
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *mut f32,
-        ldab: *const c_int,
-        ipiv: *mut c_int,
+        ldab: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgbsv_64_")]
     pub fn zgbsv_(
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *mut __BindgenComplex<f64>,
-        ldab: *const c_int,
-        ipiv: *mut c_int,
+        ldab: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgbsvx_64_")]
     pub fn cgbsvx_(
         fact: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *mut __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *mut __BindgenComplex<f32>,
-        ldafb: *const c_int,
-        ipiv: *mut c_int,
+        ldafb: *const lapack_int,
+        ipiv: *mut lapack_int,
         equed: *mut c_char,
         R: *mut f32,
         C: *mut f32,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgbsvx_64_")]
     pub fn dgbsvx_(
         fact: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *mut f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *mut f64,
-        ldafb: *const c_int,
-        ipiv: *mut c_int,
+        ldafb: *const lapack_int,
+        ipiv: *mut lapack_int,
         equed: *mut c_char,
         R: *mut f64,
         C: *mut f64,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgbsvx_64_")]
     pub fn sgbsvx_(
         fact: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *mut f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *mut f32,
-        ldafb: *const c_int,
-        ipiv: *mut c_int,
+        ldafb: *const lapack_int,
+        ipiv: *mut lapack_int,
         equed: *mut c_char,
         R: *mut f32,
         C: *mut f32,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgbsvx_64_")]
     pub fn zgbsvx_(
         fact: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *mut __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *mut __BindgenComplex<f64>,
-        ldafb: *const c_int,
-        ipiv: *mut c_int,
+        ldafb: *const lapack_int,
+        ipiv: *mut lapack_int,
         equed: *mut c_char,
         R: *mut f64,
         C: *mut f64,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgbsvxx_64_")]
     pub fn cgbsvxx_(
         fact: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *mut __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *mut __BindgenComplex<f32>,
-        ldafb: *const c_int,
-        ipiv: *mut c_int,
+        ldafb: *const lapack_int,
+        ipiv: *mut lapack_int,
         equed: *mut c_char,
         R: *mut f32,
         C: *mut f32,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         rpvgrw: *mut f32,
         berr: *mut f32,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f32,
         err_bnds_comp: *mut f32,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgbsvxx_64_")]
     pub fn dgbsvxx_(
         fact: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *mut f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *mut f64,
-        ldafb: *const c_int,
-        ipiv: *mut c_int,
+        ldafb: *const lapack_int,
+        ipiv: *mut lapack_int,
         equed: *mut c_char,
         R: *mut f64,
         C: *mut f64,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         rpvgrw: *mut f64,
         berr: *mut f64,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f64,
         err_bnds_comp: *mut f64,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgbsvxx_64_")]
     pub fn sgbsvxx_(
         fact: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *mut f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *mut f32,
-        ldafb: *const c_int,
-        ipiv: *mut c_int,
+        ldafb: *const lapack_int,
+        ipiv: *mut lapack_int,
         equed: *mut c_char,
         R: *mut f32,
         C: *mut f32,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         rpvgrw: *mut f32,
         berr: *mut f32,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f32,
         err_bnds_comp: *mut f32,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgbsvxx_64_")]
     pub fn zgbsvxx_(
         fact: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *mut __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *mut __BindgenComplex<f64>,
-        ldafb: *const c_int,
-        ipiv: *mut c_int,
+        ldafb: *const lapack_int,
+        ipiv: *mut lapack_int,
         equed: *mut c_char,
         R: *mut f64,
         C: *mut f64,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         rpvgrw: *mut f64,
         berr: *mut f64,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f64,
         err_bnds_comp: *mut f64,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgbtrf_64_")]
     pub fn cgbtrf_(
-        m: *const c_int,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *mut __BindgenComplex<f32>,
-        ldab: *const c_int,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        ldab: *const lapack_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgbtrf_64_")]
     pub fn dgbtrf_(
-        m: *const c_int,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *mut f64,
-        ldab: *const c_int,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        ldab: *const lapack_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgbtrf_64_")]
     pub fn sgbtrf_(
-        m: *const c_int,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *mut f32,
-        ldab: *const c_int,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        ldab: *const lapack_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgbtrf_64_")]
     pub fn zgbtrf_(
-        m: *const c_int,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *mut __BindgenComplex<f64>,
-        ldab: *const c_int,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        ldab: *const lapack_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgbtrs_64_")]
     pub fn cgbtrs_(
         trans: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const __BindgenComplex<f32>,
-        ldab: *const c_int,
-        ipiv: *const c_int,
+        ldab: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgbtrs_64_")]
     pub fn dgbtrs_(
         trans: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const f64,
-        ldab: *const c_int,
-        ipiv: *const c_int,
+        ldab: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgbtrs_64_")]
     pub fn sgbtrs_(
         trans: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const f32,
-        ldab: *const c_int,
-        ipiv: *const c_int,
+        ldab: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgbtrs_64_")]
     pub fn zgbtrs_(
         trans: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const __BindgenComplex<f64>,
-        ldab: *const c_int,
-        ipiv: *const c_int,
+        ldab: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgebak_64_")]
     pub fn cgebak_(
         job: *const c_char,
         side: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         scale: *const f32,
-        m: *const c_int,
+        m: *const lapack_int,
         V: *mut __BindgenComplex<f32>,
-        ldv: *const c_int,
-        info: *mut c_int,
+        ldv: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgebak_64_")]
     pub fn dgebak_(
         job: *const c_char,
         side: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         scale: *const f64,
-        m: *const c_int,
+        m: *const lapack_int,
         V: *mut f64,
-        ldv: *const c_int,
-        info: *mut c_int,
+        ldv: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgebak_64_")]
     pub fn sgebak_(
         job: *const c_char,
         side: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         scale: *const f32,
-        m: *const c_int,
+        m: *const lapack_int,
         V: *mut f32,
-        ldv: *const c_int,
-        info: *mut c_int,
+        ldv: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgebak_64_")]
     pub fn zgebak_(
         job: *const c_char,
         side: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         scale: *const f64,
-        m: *const c_int,
+        m: *const lapack_int,
         V: *mut __BindgenComplex<f64>,
-        ldv: *const c_int,
-        info: *mut c_int,
+        ldv: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgebal_64_")]
     pub fn cgebal_(
         job: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ilo: *mut c_int,
-        ihi: *mut c_int,
+        lda: *const lapack_int,
+        ilo: *mut lapack_int,
+        ihi: *mut lapack_int,
         scale: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgebal_64_")]
     pub fn dgebal_(
         job: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        ilo: *mut c_int,
-        ihi: *mut c_int,
+        lda: *const lapack_int,
+        ilo: *mut lapack_int,
+        ihi: *mut lapack_int,
         scale: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgebal_64_")]
     pub fn sgebal_(
         job: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        ilo: *mut c_int,
-        ihi: *mut c_int,
+        lda: *const lapack_int,
+        ilo: *mut lapack_int,
+        ihi: *mut lapack_int,
         scale: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgebal_64_")]
     pub fn zgebal_(
         job: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ilo: *mut c_int,
-        ihi: *mut c_int,
+        lda: *const lapack_int,
+        ilo: *mut lapack_int,
+        ihi: *mut lapack_int,
         scale: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgebrd_64_")]
     pub fn cgebrd_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         tauq: *mut __BindgenComplex<f32>,
         taup: *mut __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgebrd_64_")]
     pub fn dgebrd_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         tauq: *mut f64,
         taup: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgebrd_64_")]
     pub fn sgebrd_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         tauq: *mut f32,
         taup: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgebrd_64_")]
     pub fn zgebrd_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         tauq: *mut __BindgenComplex<f64>,
         taup: *mut __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgecon_64_")]
     pub fn cgecon_(
         norm: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         anorm: *const f32,
         rcond: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgecon_64_")]
     pub fn dgecon_(
         norm: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         anorm: *const f64,
         rcond: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgecon_64_")]
     pub fn sgecon_(
         norm: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         anorm: *const f32,
         rcond: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgecon_64_")]
     pub fn zgecon_(
         norm: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         anorm: *const f64,
         rcond: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgeequ_64_")]
     pub fn cgeequ_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         R: *mut f32,
         C: *mut f32,
         rowcnd: *mut f32,
         colcnd: *mut f32,
         amax: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgeequ_64_")]
     pub fn dgeequ_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         R: *mut f64,
         C: *mut f64,
         rowcnd: *mut f64,
         colcnd: *mut f64,
         amax: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgeequ_64_")]
     pub fn sgeequ_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         R: *mut f32,
         C: *mut f32,
         rowcnd: *mut f32,
         colcnd: *mut f32,
         amax: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgeequ_64_")]
     pub fn zgeequ_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         R: *mut f64,
         C: *mut f64,
         rowcnd: *mut f64,
         colcnd: *mut f64,
         amax: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgeequb_64_")]
     pub fn cgeequb_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         R: *mut f32,
         C: *mut f32,
         rowcnd: *mut f32,
         colcnd: *mut f32,
         amax: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgeequb_64_")]
     pub fn dgeequb_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         R: *mut f64,
         C: *mut f64,
         rowcnd: *mut f64,
         colcnd: *mut f64,
         amax: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgeequb_64_")]
     pub fn sgeequb_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         R: *mut f32,
         C: *mut f32,
         rowcnd: *mut f32,
         colcnd: *mut f32,
         amax: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgeequb_64_")]
     pub fn zgeequb_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         R: *mut f64,
         C: *mut f64,
         rowcnd: *mut f64,
         colcnd: *mut f64,
         amax: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgees_64_")]
     pub fn cgees_(
         jobvs: *const c_char,
         sort: *const c_char,
         select: LAPACK_C_SELECT1,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        sdim: *mut c_int,
+        lda: *const lapack_int,
+        sdim: *mut lapack_int,
         W: *mut __BindgenComplex<f32>,
         VS: *mut __BindgenComplex<f32>,
-        ldvs: *const c_int,
+        ldvs: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgees_64_")]
     pub fn dgees_(
         jobvs: *const c_char,
         sort: *const c_char,
         select: LAPACK_D_SELECT2,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        sdim: *mut c_int,
+        lda: *const lapack_int,
+        sdim: *mut lapack_int,
         WR: *mut f64,
         WI: *mut f64,
         VS: *mut f64,
-        ldvs: *const c_int,
+        ldvs: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgees_64_")]
     pub fn sgees_(
         jobvs: *const c_char,
         sort: *const c_char,
         select: LAPACK_S_SELECT2,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        sdim: *mut c_int,
+        lda: *const lapack_int,
+        sdim: *mut lapack_int,
         WR: *mut f32,
         WI: *mut f32,
         VS: *mut f32,
-        ldvs: *const c_int,
+        ldvs: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgees_64_")]
     pub fn zgees_(
         jobvs: *const c_char,
         sort: *const c_char,
         select: LAPACK_Z_SELECT1,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        sdim: *mut c_int,
+        lda: *const lapack_int,
+        sdim: *mut lapack_int,
         W: *mut __BindgenComplex<f64>,
         VS: *mut __BindgenComplex<f64>,
-        ldvs: *const c_int,
+        ldvs: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgeesx_64_")]
     pub fn cgeesx_(
         jobvs: *const c_char,
         sort: *const c_char,
         select: LAPACK_C_SELECT1,
         sense: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        sdim: *mut c_int,
+        lda: *const lapack_int,
+        sdim: *mut lapack_int,
         W: *mut __BindgenComplex<f32>,
         VS: *mut __BindgenComplex<f32>,
-        ldvs: *const c_int,
+        ldvs: *const lapack_int,
         rconde: *mut f32,
         rcondv: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgeesx_64_")]
     pub fn dgeesx_(
         jobvs: *const c_char,
         sort: *const c_char,
         select: LAPACK_D_SELECT2,
         sense: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        sdim: *mut c_int,
+        lda: *const lapack_int,
+        sdim: *mut lapack_int,
         WR: *mut f64,
         WI: *mut f64,
         VS: *mut f64,
-        ldvs: *const c_int,
+        ldvs: *const lapack_int,
         rconde: *mut f64,
         rcondv: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgeesx_64_")]
     pub fn sgeesx_(
         jobvs: *const c_char,
         sort: *const c_char,
         select: LAPACK_S_SELECT2,
         sense: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        sdim: *mut c_int,
+        lda: *const lapack_int,
+        sdim: *mut lapack_int,
         WR: *mut f32,
         WI: *mut f32,
         VS: *mut f32,
-        ldvs: *const c_int,
+        ldvs: *const lapack_int,
         rconde: *mut f32,
         rcondv: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgeesx_64_")]
     pub fn zgeesx_(
         jobvs: *const c_char,
         sort: *const c_char,
         select: LAPACK_Z_SELECT1,
         sense: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        sdim: *mut c_int,
+        lda: *const lapack_int,
+        sdim: *mut lapack_int,
         W: *mut __BindgenComplex<f64>,
         VS: *mut __BindgenComplex<f64>,
-        ldvs: *const c_int,
+        ldvs: *const lapack_int,
         rconde: *mut f64,
         rcondv: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgeev_64_")]
     pub fn cgeev_(
         jobvl: *const c_char,
         jobvr: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         W: *mut __BindgenComplex<f32>,
         VL: *mut __BindgenComplex<f32>,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut __BindgenComplex<f32>,
-        ldvr: *const c_int,
+        ldvr: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgeev_64_")]
     pub fn dgeev_(
         jobvl: *const c_char,
         jobvr: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         WR: *mut f64,
         WI: *mut f64,
         VL: *mut f64,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut f64,
-        ldvr: *const c_int,
+        ldvr: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgeev_64_")]
     pub fn sgeev_(
         jobvl: *const c_char,
         jobvr: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         WR: *mut f32,
         WI: *mut f32,
         VL: *mut f32,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut f32,
-        ldvr: *const c_int,
+        ldvr: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgeev_64_")]
     pub fn zgeev_(
         jobvl: *const c_char,
         jobvr: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         W: *mut __BindgenComplex<f64>,
         VL: *mut __BindgenComplex<f64>,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut __BindgenComplex<f64>,
-        ldvr: *const c_int,
+        ldvr: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgeevx_64_")]
     pub fn cgeevx_(
         balanc: *const c_char,
         jobvl: *const c_char,
         jobvr: *const c_char,
         sense: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         W: *mut __BindgenComplex<f32>,
         VL: *mut __BindgenComplex<f32>,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut __BindgenComplex<f32>,
-        ldvr: *const c_int,
-        ilo: *mut c_int,
-        ihi: *mut c_int,
+        ldvr: *const lapack_int,
+        ilo: *mut lapack_int,
+        ihi: *mut lapack_int,
         scale: *mut f32,
         abnrm: *mut f32,
         rconde: *mut f32,
         rcondv: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgeevx_64_")]
     pub fn dgeevx_(
         balanc: *const c_char,
         jobvl: *const c_char,
         jobvr: *const c_char,
         sense: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         WR: *mut f64,
         WI: *mut f64,
         VL: *mut f64,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut f64,
-        ldvr: *const c_int,
-        ilo: *mut c_int,
-        ihi: *mut c_int,
+        ldvr: *const lapack_int,
+        ilo: *mut lapack_int,
+        ihi: *mut lapack_int,
         scale: *mut f64,
         abnrm: *mut f64,
         rconde: *mut f64,
         rcondv: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgeevx_64_")]
     pub fn sgeevx_(
         balanc: *const c_char,
         jobvl: *const c_char,
         jobvr: *const c_char,
         sense: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         WR: *mut f32,
         WI: *mut f32,
         VL: *mut f32,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut f32,
-        ldvr: *const c_int,
-        ilo: *mut c_int,
-        ihi: *mut c_int,
+        ldvr: *const lapack_int,
+        ilo: *mut lapack_int,
+        ihi: *mut lapack_int,
         scale: *mut f32,
         abnrm: *mut f32,
         rconde: *mut f32,
         rcondv: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgeevx_64_")]
     pub fn zgeevx_(
         balanc: *const c_char,
         jobvl: *const c_char,
         jobvr: *const c_char,
         sense: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         W: *mut __BindgenComplex<f64>,
         VL: *mut __BindgenComplex<f64>,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut __BindgenComplex<f64>,
-        ldvr: *const c_int,
-        ilo: *mut c_int,
-        ihi: *mut c_int,
+        ldvr: *const lapack_int,
+        ilo: *mut lapack_int,
+        ihi: *mut lapack_int,
         scale: *mut f64,
         abnrm: *mut f64,
         rconde: *mut f64,
         rcondv: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgehrd_64_")]
     pub fn cgehrd_(
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgehrd_64_")]
     pub fn dgehrd_(
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgehrd_64_")]
     pub fn sgehrd_(
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgehrd_64_")]
     pub fn zgehrd_(
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgejsv_64_")]
     pub fn cgejsv_(
         joba: *const c_char,
         jobu: *const c_char,
@@ -1982,24 +2087,25 @@ extern "C" {
         jobr: *const c_char,
         jobt: *const c_char,
         jobp: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         SVA: *mut f32,
         U: *mut __BindgenComplex<f32>,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         V: *mut __BindgenComplex<f32>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         cwork: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgejsv_64_")]
     pub fn dgejsv_(
         joba: *const c_char,
         jobu: *const c_char,
@@ -2007,22 +2113,23 @@ extern "C" {
         jobr: *const c_char,
         jobt: *const c_char,
         jobp: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         SVA: *mut f64,
         U: *mut f64,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         V: *mut f64,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgejsv_64_")]
     pub fn sgejsv_(
         joba: *const c_char,
         jobu: *const c_char,
@@ -2030,22 +2137,23 @@ extern "C" {
         jobr: *const c_char,
         jobt: *const c_char,
         jobp: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         SVA: *mut f32,
         U: *mut f32,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         V: *mut f32,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgejsv_64_")]
     pub fn zgejsv_(
         joba: *const c_char,
         jobu: *const c_char,
@@ -2053,3190 +2161,3385 @@ extern "C" {
         jobr: *const c_char,
         jobt: *const c_char,
         jobp: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         SVA: *mut f64,
         U: *mut __BindgenComplex<f64>,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         V: *mut __BindgenComplex<f64>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         cwork: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgelq_64_")]
     pub fn cgelq_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut __BindgenComplex<f32>,
-        tsize: *const c_int,
+        tsize: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgelq_64_")]
     pub fn dgelq_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut f64,
-        tsize: *const c_int,
+        tsize: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgelq_64_")]
     pub fn sgelq_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut f32,
-        tsize: *const c_int,
+        tsize: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgelq_64_")]
     pub fn zgelq_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut __BindgenComplex<f64>,
-        tsize: *const c_int,
+        tsize: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgelq2_64_")]
     pub fn cgelq2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgelq2_64_")]
     pub fn dgelq2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut f64,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgelq2_64_")]
     pub fn sgelq2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut f32,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgelq2_64_")]
     pub fn zgelq2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgelqf_64_")]
     pub fn cgelqf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgelqf_64_")]
     pub fn dgelqf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgelqf_64_")]
     pub fn sgelqf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgelqf_64_")]
     pub fn zgelqf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgels_64_")]
     pub fn cgels_(
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        nrhs: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgels_64_")]
     pub fn dgels_(
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        nrhs: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
         arg1: size_t,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgels_64_")]
     pub fn sgels_(
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        nrhs: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgels_64_")]
     pub fn zgels_(
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        nrhs: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgelsd_64_")]
     pub fn cgelsd_(
-        m: *const c_int,
-        n: *const c_int,
-        nrhs: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         S: *mut f32,
         rcond: *const f32,
-        rank: *mut c_int,
+        rank: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgelsd_64_")]
     pub fn dgelsd_(
-        m: *const c_int,
-        n: *const c_int,
-        nrhs: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         S: *mut f64,
         rcond: *const f64,
-        rank: *mut c_int,
+        rank: *mut lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgelsd_64_")]
     pub fn sgelsd_(
-        m: *const c_int,
-        n: *const c_int,
-        nrhs: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         S: *mut f32,
         rcond: *const f32,
-        rank: *mut c_int,
+        rank: *mut lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgelsd_64_")]
     pub fn zgelsd_(
-        m: *const c_int,
-        n: *const c_int,
-        nrhs: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         S: *mut f64,
         rcond: *const f64,
-        rank: *mut c_int,
+        rank: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgelss_64_")]
     pub fn cgelss_(
-        m: *const c_int,
-        n: *const c_int,
-        nrhs: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         S: *mut f32,
         rcond: *const f32,
-        rank: *mut c_int,
+        rank: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgelss_64_")]
     pub fn dgelss_(
-        m: *const c_int,
-        n: *const c_int,
-        nrhs: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         S: *mut f64,
         rcond: *const f64,
-        rank: *mut c_int,
+        rank: *mut lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgelss_64_")]
     pub fn sgelss_(
-        m: *const c_int,
-        n: *const c_int,
-        nrhs: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         S: *mut f32,
         rcond: *const f32,
-        rank: *mut c_int,
+        rank: *mut lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgelss_64_")]
     pub fn zgelss_(
-        m: *const c_int,
-        n: *const c_int,
-        nrhs: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         S: *mut f64,
         rcond: *const f64,
-        rank: *mut c_int,
+        rank: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgelsy_64_")]
     pub fn cgelsy_(
-        m: *const c_int,
-        n: *const c_int,
-        nrhs: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        JPVT: *mut c_int,
+        ldb: *const lapack_int,
+        JPVT: *mut lapack_int,
         rcond: *const f32,
-        rank: *mut c_int,
+        rank: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgelsy_64_")]
     pub fn dgelsy_(
-        m: *const c_int,
-        n: *const c_int,
-        nrhs: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
-        JPVT: *mut c_int,
+        ldb: *const lapack_int,
+        JPVT: *mut lapack_int,
         rcond: *const f64,
-        rank: *mut c_int,
+        rank: *mut lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgelsy_64_")]
     pub fn sgelsy_(
-        m: *const c_int,
-        n: *const c_int,
-        nrhs: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
-        JPVT: *mut c_int,
+        ldb: *const lapack_int,
+        JPVT: *mut lapack_int,
         rcond: *const f32,
-        rank: *mut c_int,
+        rank: *mut lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgelsy_64_")]
     pub fn zgelsy_(
-        m: *const c_int,
-        n: *const c_int,
-        nrhs: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        JPVT: *mut c_int,
+        ldb: *const lapack_int,
+        JPVT: *mut lapack_int,
         rcond: *const f64,
-        rank: *mut c_int,
+        rank: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgemlq_64_")]
     pub fn cgemlq_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *const __BindgenComplex<f32>,
-        tsize: *const c_int,
+        tsize: *const lapack_int,
         C: *mut __BindgenComplex<f32>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgemlq_64_")]
     pub fn dgemlq_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *const f64,
-        tsize: *const c_int,
+        tsize: *const lapack_int,
         C: *mut f64,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgemlq_64_")]
     pub fn sgemlq_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *const f32,
-        tsize: *const c_int,
+        tsize: *const lapack_int,
         C: *mut f32,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgemlq_64_")]
     pub fn zgemlq_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *const __BindgenComplex<f64>,
-        tsize: *const c_int,
+        tsize: *const lapack_int,
         C: *mut __BindgenComplex<f64>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgemqr_64_")]
     pub fn cgemqr_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *const __BindgenComplex<f32>,
-        tsize: *const c_int,
+        tsize: *const lapack_int,
         C: *mut __BindgenComplex<f32>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgemqr_64_")]
     pub fn dgemqr_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *const f64,
-        tsize: *const c_int,
+        tsize: *const lapack_int,
         C: *mut f64,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgemqr_64_")]
     pub fn sgemqr_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *const f32,
-        tsize: *const c_int,
+        tsize: *const lapack_int,
         C: *mut f32,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgemqr_64_")]
     pub fn zgemqr_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *const __BindgenComplex<f64>,
-        tsize: *const c_int,
+        tsize: *const lapack_int,
         C: *mut __BindgenComplex<f64>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgemqrt_64_")]
     pub fn cgemqrt_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        nb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        nb: *const lapack_int,
         V: *const __BindgenComplex<f32>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         T: *const __BindgenComplex<f32>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         C: *mut __BindgenComplex<f32>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgemqrt_64_")]
     pub fn dgemqrt_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        nb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        nb: *const lapack_int,
         V: *const f64,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         T: *const f64,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         C: *mut f64,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgemqrt_64_")]
     pub fn sgemqrt_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        nb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        nb: *const lapack_int,
         V: *const f32,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         T: *const f32,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         C: *mut f32,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgemqrt_64_")]
     pub fn zgemqrt_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        nb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        nb: *const lapack_int,
         V: *const __BindgenComplex<f64>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         T: *const __BindgenComplex<f64>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         C: *mut __BindgenComplex<f64>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgeql2_64_")]
     pub fn cgeql2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgeql2_64_")]
     pub fn dgeql2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut f64,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgeql2_64_")]
     pub fn sgeql2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut f32,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgeql2_64_")]
     pub fn zgeql2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgeqlf_64_")]
     pub fn cgeqlf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgeqlf_64_")]
     pub fn dgeqlf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgeqlf_64_")]
     pub fn sgeqlf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgeqlf_64_")]
     pub fn zgeqlf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[deprecated(note = "use sgeqp3_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgeqpf_64_")]
     pub fn sgeqpf_(
-        m: *mut c_int,
-        n: *mut c_int,
+        m: *mut lapack_int,
+        n: *mut lapack_int,
         a: *mut f32,
-        lda: *mut c_int,
-        jpvt: *mut c_int,
+        lda: *mut lapack_int,
+        jpvt: *mut lapack_int,
         tau: *mut f32,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[deprecated(note = "use dgeqp3_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgeqpf_64_")]
     pub fn dgeqpf_(
-        m: *mut c_int,
-        n: *mut c_int,
+        m: *mut lapack_int,
+        n: *mut lapack_int,
         a: *mut f64,
-        lda: *mut c_int,
-        jpvt: *mut c_int,
+        lda: *mut lapack_int,
+        jpvt: *mut lapack_int,
         tau: *mut f64,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[deprecated(note = "use cgeqp3_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgeqpf_64_")]
     pub fn cgeqpf_(
-        m: *mut c_int,
-        n: *mut c_int,
+        m: *mut lapack_int,
+        n: *mut lapack_int,
         a: *mut __BindgenComplex<f32>,
-        lda: *mut c_int,
-        jpvt: *mut c_int,
+        lda: *mut lapack_int,
+        jpvt: *mut lapack_int,
         tau: *mut __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[deprecated(note = "use zgeqp3_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgeqpf_64_")]
     pub fn zgeqpf_(
-        m: *mut c_int,
-        n: *mut c_int,
+        m: *mut lapack_int,
+        n: *mut lapack_int,
         a: *mut __BindgenComplex<f64>,
-        lda: *mut c_int,
-        jpvt: *mut c_int,
+        lda: *mut lapack_int,
+        jpvt: *mut lapack_int,
         tau: *mut __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgeqp3_64_")]
     pub fn cgeqp3_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        JPVT: *mut c_int,
+        lda: *const lapack_int,
+        JPVT: *mut lapack_int,
         tau: *mut __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgeqp3_64_")]
     pub fn dgeqp3_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        JPVT: *mut c_int,
+        lda: *const lapack_int,
+        JPVT: *mut lapack_int,
         tau: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgeqp3_64_")]
     pub fn sgeqp3_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        JPVT: *mut c_int,
+        lda: *const lapack_int,
+        JPVT: *mut lapack_int,
         tau: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgeqp3_64_")]
     pub fn zgeqp3_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        JPVT: *mut c_int,
+        lda: *const lapack_int,
+        JPVT: *mut lapack_int,
         tau: *mut __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgeqr_64_")]
     pub fn cgeqr_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut __BindgenComplex<f32>,
-        tsize: *const c_int,
+        tsize: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgeqr_64_")]
     pub fn dgeqr_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut f64,
-        tsize: *const c_int,
+        tsize: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgeqr_64_")]
     pub fn sgeqr_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut f32,
-        tsize: *const c_int,
+        tsize: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgeqr_64_")]
     pub fn zgeqr_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut __BindgenComplex<f64>,
-        tsize: *const c_int,
+        tsize: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgeqr2_64_")]
     pub fn cgeqr2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgeqr2_64_")]
     pub fn dgeqr2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut f64,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgeqr2_64_")]
     pub fn sgeqr2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut f32,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgeqr2_64_")]
     pub fn zgeqr2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgeqrf_64_")]
     pub fn cgeqrf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgeqrf_64_")]
     pub fn dgeqrf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgeqrf_64_")]
     pub fn sgeqrf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgeqrf_64_")]
     pub fn zgeqrf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgeqrfp_64_")]
     pub fn cgeqrfp_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgeqrfp_64_")]
     pub fn dgeqrfp_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgeqrfp_64_")]
     pub fn sgeqrfp_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgeqrfp_64_")]
     pub fn zgeqrfp_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgeqrt_64_")]
     pub fn cgeqrt_(
-        m: *const c_int,
-        n: *const c_int,
-        nb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nb: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut __BindgenComplex<f32>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgeqrt_64_")]
     pub fn dgeqrt_(
-        m: *const c_int,
-        n: *const c_int,
-        nb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nb: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut f64,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgeqrt_64_")]
     pub fn sgeqrt_(
-        m: *const c_int,
-        n: *const c_int,
-        nb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nb: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut f32,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgeqrt_64_")]
     pub fn zgeqrt_(
-        m: *const c_int,
-        n: *const c_int,
-        nb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nb: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut __BindgenComplex<f64>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgeqrt2_64_")]
     pub fn cgeqrt2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut __BindgenComplex<f32>,
-        ldt: *const c_int,
-        info: *mut c_int,
+        ldt: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgeqrt2_64_")]
     pub fn dgeqrt2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut f64,
-        ldt: *const c_int,
-        info: *mut c_int,
+        ldt: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgeqrt2_64_")]
     pub fn sgeqrt2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut f32,
-        ldt: *const c_int,
-        info: *mut c_int,
+        ldt: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgeqrt2_64_")]
     pub fn zgeqrt2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut __BindgenComplex<f64>,
-        ldt: *const c_int,
-        info: *mut c_int,
+        ldt: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgeqrt3_64_")]
     pub fn cgeqrt3_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut __BindgenComplex<f32>,
-        ldt: *const c_int,
-        info: *mut c_int,
+        ldt: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgeqrt3_64_")]
     pub fn dgeqrt3_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut f64,
-        ldt: *const c_int,
-        info: *mut c_int,
+        ldt: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgeqrt3_64_")]
     pub fn sgeqrt3_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut f32,
-        ldt: *const c_int,
-        info: *mut c_int,
+        ldt: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgeqrt3_64_")]
     pub fn zgeqrt3_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut __BindgenComplex<f64>,
-        ldt: *const c_int,
-        info: *mut c_int,
+        ldt: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgerfs_64_")]
     pub fn cgerfs_(
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const __BindgenComplex<f32>,
-        ldaf: *const c_int,
-        ipiv: *const c_int,
+        ldaf: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgerfs_64_")]
     pub fn dgerfs_(
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const f64,
-        ldaf: *const c_int,
-        ipiv: *const c_int,
+        ldaf: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgerfs_64_")]
     pub fn sgerfs_(
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const f32,
-        ldaf: *const c_int,
-        ipiv: *const c_int,
+        ldaf: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgerfs_64_")]
     pub fn zgerfs_(
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const __BindgenComplex<f64>,
-        ldaf: *const c_int,
-        ipiv: *const c_int,
+        ldaf: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgerfsx_64_")]
     pub fn cgerfsx_(
         trans: *const c_char,
         equed: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const __BindgenComplex<f32>,
-        ldaf: *const c_int,
-        ipiv: *const c_int,
+        ldaf: *const lapack_int,
+        ipiv: *const lapack_int,
         R: *const f32,
         C: *const f32,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         berr: *mut f32,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f32,
         err_bnds_comp: *mut f32,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgerfsx_64_")]
     pub fn dgerfsx_(
         trans: *const c_char,
         equed: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const f64,
-        ldaf: *const c_int,
-        ipiv: *const c_int,
+        ldaf: *const lapack_int,
+        ipiv: *const lapack_int,
         R: *const f64,
         C: *const f64,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         berr: *mut f64,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f64,
         err_bnds_comp: *mut f64,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgerfsx_64_")]
     pub fn sgerfsx_(
         trans: *const c_char,
         equed: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const f32,
-        ldaf: *const c_int,
-        ipiv: *const c_int,
+        ldaf: *const lapack_int,
+        ipiv: *const lapack_int,
         R: *const f32,
         C: *const f32,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         berr: *mut f32,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f32,
         err_bnds_comp: *mut f32,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgerfsx_64_")]
     pub fn zgerfsx_(
         trans: *const c_char,
         equed: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const __BindgenComplex<f64>,
-        ldaf: *const c_int,
-        ipiv: *const c_int,
+        ldaf: *const lapack_int,
+        ipiv: *const lapack_int,
         R: *const f64,
         C: *const f64,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         berr: *mut f64,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f64,
         err_bnds_comp: *mut f64,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgerq2_64_")]
     pub fn cgerq2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgerq2_64_")]
     pub fn dgerq2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut f64,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgerq2_64_")]
     pub fn sgerq2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut f32,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgerq2_64_")]
     pub fn zgerq2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgerqf_64_")]
     pub fn cgerqf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgerqf_64_")]
     pub fn dgerqf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgerqf_64_")]
     pub fn sgerqf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgerqf_64_")]
     pub fn zgerqf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgesdd_64_")]
     pub fn cgesdd_(
         jobz: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f32,
         U: *mut __BindgenComplex<f32>,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         VT: *mut __BindgenComplex<f32>,
-        ldvt: *const c_int,
+        ldvt: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgesdd_64_")]
     pub fn dgesdd_(
         jobz: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f64,
         U: *mut f64,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         VT: *mut f64,
-        ldvt: *const c_int,
+        ldvt: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgesdd_64_")]
     pub fn sgesdd_(
         jobz: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f32,
         U: *mut f32,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         VT: *mut f32,
-        ldvt: *const c_int,
+        ldvt: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgesdd_64_")]
     pub fn zgesdd_(
         jobz: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f64,
         U: *mut __BindgenComplex<f64>,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         VT: *mut __BindgenComplex<f64>,
-        ldvt: *const c_int,
+        ldvt: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgesv_64_")]
     pub fn cgesv_(
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgesv_64_")]
     pub fn dgesv_(
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgesv_64_")]
     pub fn sgesv_(
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgesv_64_")]
     pub fn zgesv_(
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsgesv_64_")]
     pub fn dsgesv_(
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         work: *mut f64,
         swork: *mut f32,
-        iter: *mut c_int,
-        info: *mut c_int,
+        iter: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zcgesv_64_")]
     pub fn zcgesv_(
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
         swork: *mut __BindgenComplex<f32>,
         rwork: *mut f64,
-        iter: *mut c_int,
-        info: *mut c_int,
+        iter: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgesvd_64_")]
     pub fn cgesvd_(
         jobu: *const c_char,
         jobvt: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f32,
         U: *mut __BindgenComplex<f32>,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         VT: *mut __BindgenComplex<f32>,
-        ldvt: *const c_int,
+        ldvt: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgesvd_64_")]
     pub fn dgesvd_(
         jobu: *const c_char,
         jobvt: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f64,
         U: *mut f64,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         VT: *mut f64,
-        ldvt: *const c_int,
+        ldvt: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgesvd_64_")]
     pub fn sgesvd_(
         jobu: *const c_char,
         jobvt: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f32,
         U: *mut f32,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         VT: *mut f32,
-        ldvt: *const c_int,
+        ldvt: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgesvd_64_")]
     pub fn zgesvd_(
         jobu: *const c_char,
         jobvt: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f64,
         U: *mut __BindgenComplex<f64>,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         VT: *mut __BindgenComplex<f64>,
-        ldvt: *const c_int,
+        ldvt: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgesvdq_64_")]
     pub fn cgesvdq_(
         joba: *const c_char,
         jobp: *const c_char,
         jobr: *const c_char,
         jobu: *const c_char,
         jobv: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f32,
         U: *mut __BindgenComplex<f32>,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         V: *mut __BindgenComplex<f32>,
-        ldv: *const c_int,
-        numrank: *mut c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
+        ldv: *const lapack_int,
+        numrank: *mut lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
         cwork: *mut __BindgenComplex<f32>,
-        lcwork: *mut c_int,
+        lcwork: *mut lapack_int,
         rwork: *mut f32,
-        lrwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgesvdq_64_")]
     pub fn dgesvdq_(
         joba: *const c_char,
         jobp: *const c_char,
         jobr: *const c_char,
         jobu: *const c_char,
         jobv: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f64,
         U: *mut f64,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         V: *mut f64,
-        ldv: *const c_int,
-        numrank: *mut c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
+        ldv: *const lapack_int,
+        numrank: *mut lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
         work: *mut f64,
-        lwork: *mut c_int,
+        lwork: *mut lapack_int,
         rwork: *mut f64,
-        lrwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgesvdq_64_")]
     pub fn sgesvdq_(
         joba: *const c_char,
         jobp: *const c_char,
         jobr: *const c_char,
         jobu: *const c_char,
         jobv: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f32,
         U: *mut f32,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         V: *mut f32,
-        ldv: *const c_int,
-        numrank: *mut c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
+        ldv: *const lapack_int,
+        numrank: *mut lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
         work: *mut f32,
-        lwork: *mut c_int,
+        lwork: *mut lapack_int,
         rwork: *mut f32,
-        lrwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgesvdq_64_")]
     pub fn zgesvdq_(
         joba: *const c_char,
         jobp: *const c_char,
         jobr: *const c_char,
         jobu: *const c_char,
         jobv: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f64,
         U: *mut __BindgenComplex<f64>,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         V: *mut __BindgenComplex<f64>,
-        ldv: *const c_int,
-        numrank: *mut c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
+        ldv: *const lapack_int,
+        numrank: *mut lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
         cwork: *mut __BindgenComplex<f64>,
-        lcwork: *mut c_int,
+        lcwork: *mut lapack_int,
         rwork: *mut f64,
-        lrwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgesvdx_64_")]
     pub fn cgesvdx_(
         jobu: *const c_char,
         jobvt: *const c_char,
         range: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
-        ns: *mut c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
+        ns: *mut lapack_int,
         S: *mut f32,
         U: *mut __BindgenComplex<f32>,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         VT: *mut __BindgenComplex<f32>,
-        ldvt: *const c_int,
+        ldvt: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgesvdx_64_")]
     pub fn dgesvdx_(
         jobu: *const c_char,
         jobvt: *const c_char,
         range: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
-        ns: *mut c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
+        ns: *mut lapack_int,
         S: *mut f64,
         U: *mut f64,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         VT: *mut f64,
-        ldvt: *const c_int,
+        ldvt: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgesvdx_64_")]
     pub fn sgesvdx_(
         jobu: *const c_char,
         jobvt: *const c_char,
         range: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
-        ns: *mut c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
+        ns: *mut lapack_int,
         S: *mut f32,
         U: *mut f32,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         VT: *mut f32,
-        ldvt: *const c_int,
+        ldvt: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgesvdx_64_")]
     pub fn zgesvdx_(
         jobu: *const c_char,
         jobvt: *const c_char,
         range: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
-        ns: *mut c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
+        ns: *mut lapack_int,
         S: *mut f64,
         U: *mut __BindgenComplex<f64>,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         VT: *mut __BindgenComplex<f64>,
-        ldvt: *const c_int,
+        ldvt: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgesvj_64_")]
     pub fn cgesvj_(
         joba: *const c_char,
         jobu: *const c_char,
         jobv: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         SVA: *mut f32,
-        mv: *const c_int,
+        mv: *const lapack_int,
         V: *mut __BindgenComplex<f32>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         cwork: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        lrwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgesvj_64_")]
     pub fn dgesvj_(
         joba: *const c_char,
         jobu: *const c_char,
         jobv: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         SVA: *mut f64,
-        mv: *const c_int,
+        mv: *const lapack_int,
         V: *mut f64,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgesvj_64_")]
     pub fn sgesvj_(
         joba: *const c_char,
         jobu: *const c_char,
         jobv: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         SVA: *mut f32,
-        mv: *const c_int,
+        mv: *const lapack_int,
         V: *mut f32,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgesvj_64_")]
     pub fn zgesvj_(
         joba: *const c_char,
         jobu: *const c_char,
         jobv: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         SVA: *mut f64,
-        mv: *const c_int,
+        mv: *const lapack_int,
         V: *mut __BindgenComplex<f64>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         cwork: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        lrwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgesvx_64_")]
     pub fn cgesvx_(
         fact: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut __BindgenComplex<f32>,
-        ldaf: *const c_int,
-        ipiv: *mut c_int,
+        ldaf: *const lapack_int,
+        ipiv: *mut lapack_int,
         equed: *mut c_char,
         R: *mut f32,
         C: *mut f32,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgesvx_64_")]
     pub fn dgesvx_(
         fact: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut f64,
-        ldaf: *const c_int,
-        ipiv: *mut c_int,
+        ldaf: *const lapack_int,
+        ipiv: *mut lapack_int,
         equed: *mut c_char,
         R: *mut f64,
         C: *mut f64,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgesvx_64_")]
     pub fn sgesvx_(
         fact: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut f32,
-        ldaf: *const c_int,
-        ipiv: *mut c_int,
+        ldaf: *const lapack_int,
+        ipiv: *mut lapack_int,
         equed: *mut c_char,
         R: *mut f32,
         C: *mut f32,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgesvx_64_")]
     pub fn zgesvx_(
         fact: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut __BindgenComplex<f64>,
-        ldaf: *const c_int,
-        ipiv: *mut c_int,
+        ldaf: *const lapack_int,
+        ipiv: *mut lapack_int,
         equed: *mut c_char,
         R: *mut f64,
         C: *mut f64,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgesvxx_64_")]
     pub fn cgesvxx_(
         fact: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut __BindgenComplex<f32>,
-        ldaf: *const c_int,
-        ipiv: *mut c_int,
+        ldaf: *const lapack_int,
+        ipiv: *mut lapack_int,
         equed: *mut c_char,
         R: *mut f32,
         C: *mut f32,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         rpvgrw: *mut f32,
         berr: *mut f32,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f32,
         err_bnds_comp: *mut f32,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgesvxx_64_")]
     pub fn dgesvxx_(
         fact: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut f64,
-        ldaf: *const c_int,
-        ipiv: *mut c_int,
+        ldaf: *const lapack_int,
+        ipiv: *mut lapack_int,
         equed: *mut c_char,
         R: *mut f64,
         C: *mut f64,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         rpvgrw: *mut f64,
         berr: *mut f64,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f64,
         err_bnds_comp: *mut f64,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgesvxx_64_")]
     pub fn sgesvxx_(
         fact: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut f32,
-        ldaf: *const c_int,
-        ipiv: *mut c_int,
+        ldaf: *const lapack_int,
+        ipiv: *mut lapack_int,
         equed: *mut c_char,
         R: *mut f32,
         C: *mut f32,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         rpvgrw: *mut f32,
         berr: *mut f32,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f32,
         err_bnds_comp: *mut f32,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgesvxx_64_")]
     pub fn zgesvxx_(
         fact: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut __BindgenComplex<f64>,
-        ldaf: *const c_int,
-        ipiv: *mut c_int,
+        ldaf: *const lapack_int,
+        ipiv: *mut lapack_int,
         equed: *mut c_char,
         R: *mut f64,
         C: *mut f64,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         rpvgrw: *mut f64,
         berr: *mut f64,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f64,
         err_bnds_comp: *mut f64,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgetf2_64_")]
     pub fn cgetf2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgetf2_64_")]
     pub fn dgetf2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgetf2_64_")]
     pub fn sgetf2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgetf2_64_")]
     pub fn zgetf2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgetrf_64_")]
     pub fn cgetrf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgetrf_64_")]
     pub fn dgetrf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgetrf_64_")]
     pub fn sgetrf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgetrf_64_")]
     pub fn zgetrf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgetrf2_64_")]
     pub fn cgetrf2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgetrf2_64_")]
     pub fn dgetrf2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgetrf2_64_")]
     pub fn sgetrf2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgetrf2_64_")]
     pub fn zgetrf2_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgetri_64_")]
     pub fn cgetri_(
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgetri_64_")]
     pub fn dgetri_(
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgetri_64_")]
     pub fn sgetri_(
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgetri_64_")]
     pub fn zgetri_(
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgetrs_64_")]
     pub fn cgetrs_(
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgetrs_64_")]
     pub fn dgetrs_(
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgetrs_64_")]
     pub fn sgetrs_(
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgetrs_64_")]
     pub fn zgetrs_(
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgetsls_64_")]
     pub fn cgetsls_(
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        nrhs: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgetsls_64_")]
     pub fn dgetsls_(
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        nrhs: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgetsls_64_")]
     pub fn sgetsls_(
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        nrhs: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgetsls_64_")]
     pub fn zgetsls_(
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        nrhs: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgetsqrhrt_64_")]
     pub fn cgetsqrhrt_(
-        m: *const c_int,
-        n: *const c_int,
-        mb1: *const c_int,
-        nb1: *const c_int,
-        nb2: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        mb1: *const lapack_int,
+        nb1: *const lapack_int,
+        nb2: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut __BindgenComplex<f32>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgetsqrhrt_64_")]
     pub fn dgetsqrhrt_(
-        m: *const c_int,
-        n: *const c_int,
-        mb1: *const c_int,
-        nb1: *const c_int,
-        nb2: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        mb1: *const lapack_int,
+        nb1: *const lapack_int,
+        nb2: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut f64,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgetsqrhrt_64_")]
     pub fn sgetsqrhrt_(
-        m: *const c_int,
-        n: *const c_int,
-        mb1: *const c_int,
-        nb1: *const c_int,
-        nb2: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        mb1: *const lapack_int,
+        nb1: *const lapack_int,
+        nb2: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut f32,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgetsqrhrt_64_")]
     pub fn zgetsqrhrt_(
-        m: *const c_int,
-        n: *const c_int,
-        mb1: *const c_int,
-        nb1: *const c_int,
-        nb2: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        mb1: *const lapack_int,
+        nb1: *const lapack_int,
+        nb2: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *mut __BindgenComplex<f64>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cggbak_64_")]
     pub fn cggbak_(
         job: *const c_char,
         side: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         lscale: *const f32,
         rscale: *const f32,
-        m: *const c_int,
+        m: *const lapack_int,
         V: *mut __BindgenComplex<f32>,
-        ldv: *const c_int,
-        info: *mut c_int,
+        ldv: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dggbak_64_")]
     pub fn dggbak_(
         job: *const c_char,
         side: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         lscale: *const f64,
         rscale: *const f64,
-        m: *const c_int,
+        m: *const lapack_int,
         V: *mut f64,
-        ldv: *const c_int,
-        info: *mut c_int,
+        ldv: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sggbak_64_")]
     pub fn sggbak_(
         job: *const c_char,
         side: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         lscale: *const f32,
         rscale: *const f32,
-        m: *const c_int,
+        m: *const lapack_int,
         V: *mut f32,
-        ldv: *const c_int,
-        info: *mut c_int,
+        ldv: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zggbak_64_")]
     pub fn zggbak_(
         job: *const c_char,
         side: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         lscale: *const f64,
         rscale: *const f64,
-        m: *const c_int,
+        m: *const lapack_int,
         V: *mut __BindgenComplex<f64>,
-        ldv: *const c_int,
-        info: *mut c_int,
+        ldv: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cggbal_64_")]
     pub fn cggbal_(
         job: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        ilo: *mut c_int,
-        ihi: *mut c_int,
+        ldb: *const lapack_int,
+        ilo: *mut lapack_int,
+        ihi: *mut lapack_int,
         lscale: *mut f32,
         rscale: *mut f32,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dggbal_64_")]
     pub fn dggbal_(
         job: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
-        ilo: *mut c_int,
-        ihi: *mut c_int,
+        ldb: *const lapack_int,
+        ilo: *mut lapack_int,
+        ihi: *mut lapack_int,
         lscale: *mut f64,
         rscale: *mut f64,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sggbal_64_")]
     pub fn sggbal_(
         job: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
-        ilo: *mut c_int,
-        ihi: *mut c_int,
+        ldb: *const lapack_int,
+        ilo: *mut lapack_int,
+        ihi: *mut lapack_int,
         lscale: *mut f32,
         rscale: *mut f32,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zggbal_64_")]
     pub fn zggbal_(
         job: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        ilo: *mut c_int,
-        ihi: *mut c_int,
+        ldb: *const lapack_int,
+        ilo: *mut lapack_int,
+        ihi: *mut lapack_int,
         lscale: *mut f64,
         rscale: *mut f64,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgges_64_")]
     pub fn cgges_(
         jobvsl: *const c_char,
         jobvsr: *const c_char,
         sort: *const c_char,
         selctg: LAPACK_C_SELECT2,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        sdim: *mut c_int,
+        ldb: *const lapack_int,
+        sdim: *mut lapack_int,
         alpha: *mut __BindgenComplex<f32>,
         beta: *mut __BindgenComplex<f32>,
         VSL: *mut __BindgenComplex<f32>,
-        ldvsl: *const c_int,
+        ldvsl: *const lapack_int,
         VSR: *mut __BindgenComplex<f32>,
-        ldvsr: *const c_int,
+        ldvsr: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgges_64_")]
     pub fn dgges_(
         jobvsl: *const c_char,
         jobvsr: *const c_char,
         sort: *const c_char,
         selctg: LAPACK_D_SELECT3,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
-        sdim: *mut c_int,
+        ldb: *const lapack_int,
+        sdim: *mut lapack_int,
         alphar: *mut f64,
         alphai: *mut f64,
         beta: *mut f64,
         VSL: *mut f64,
-        ldvsl: *const c_int,
+        ldvsl: *const lapack_int,
         VSR: *mut f64,
-        ldvsr: *const c_int,
+        ldvsr: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgges_64_")]
     pub fn sgges_(
         jobvsl: *const c_char,
         jobvsr: *const c_char,
         sort: *const c_char,
         selctg: LAPACK_S_SELECT3,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
-        sdim: *mut c_int,
+        ldb: *const lapack_int,
+        sdim: *mut lapack_int,
         alphar: *mut f32,
         alphai: *mut f32,
         beta: *mut f32,
         VSL: *mut f32,
-        ldvsl: *const c_int,
+        ldvsl: *const lapack_int,
         VSR: *mut f32,
-        ldvsr: *const c_int,
+        ldvsr: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgges_64_")]
     pub fn zgges_(
         jobvsl: *const c_char,
         jobvsr: *const c_char,
         sort: *const c_char,
         selctg: LAPACK_Z_SELECT2,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        sdim: *mut c_int,
+        ldb: *const lapack_int,
+        sdim: *mut lapack_int,
         alpha: *mut __BindgenComplex<f64>,
         beta: *mut __BindgenComplex<f64>,
         VSL: *mut __BindgenComplex<f64>,
-        ldvsl: *const c_int,
+        ldvsl: *const lapack_int,
         VSR: *mut __BindgenComplex<f64>,
-        ldvsr: *const c_int,
+        ldvsr: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgges3_64_")]
     pub fn cgges3_(
         jobvsl: *const c_char,
         jobvsr: *const c_char,
         sort: *const c_char,
         selctg: LAPACK_C_SELECT2,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        sdim: *mut c_int,
+        ldb: *const lapack_int,
+        sdim: *mut lapack_int,
         alpha: *mut __BindgenComplex<f32>,
         beta: *mut __BindgenComplex<f32>,
         VSL: *mut __BindgenComplex<f32>,
-        ldvsl: *const c_int,
+        ldvsl: *const lapack_int,
         VSR: *mut __BindgenComplex<f32>,
-        ldvsr: *const c_int,
+        ldvsr: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgges3_64_")]
     pub fn dgges3_(
         jobvsl: *const c_char,
         jobvsr: *const c_char,
         sort: *const c_char,
         selctg: LAPACK_D_SELECT3,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
-        sdim: *mut c_int,
+        ldb: *const lapack_int,
+        sdim: *mut lapack_int,
         alphar: *mut f64,
         alphai: *mut f64,
         beta: *mut f64,
         VSL: *mut f64,
-        ldvsl: *const c_int,
+        ldvsl: *const lapack_int,
         VSR: *mut f64,
-        ldvsr: *const c_int,
+        ldvsr: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgges3_64_")]
     pub fn sgges3_(
         jobvsl: *const c_char,
         jobvsr: *const c_char,
         sort: *const c_char,
         selctg: LAPACK_S_SELECT3,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
-        sdim: *mut c_int,
+        ldb: *const lapack_int,
+        sdim: *mut lapack_int,
         alphar: *mut f32,
         alphai: *mut f32,
         beta: *mut f32,
         VSL: *mut f32,
-        ldvsl: *const c_int,
+        ldvsl: *const lapack_int,
         VSR: *mut f32,
-        ldvsr: *const c_int,
+        ldvsr: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgges3_64_")]
     pub fn zgges3_(
         jobvsl: *const c_char,
         jobvsr: *const c_char,
         sort: *const c_char,
         selctg: LAPACK_Z_SELECT2,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        sdim: *mut c_int,
+        ldb: *const lapack_int,
+        sdim: *mut lapack_int,
         alpha: *mut __BindgenComplex<f64>,
         beta: *mut __BindgenComplex<f64>,
         VSL: *mut __BindgenComplex<f64>,
-        ldvsl: *const c_int,
+        ldvsl: *const lapack_int,
         VSR: *mut __BindgenComplex<f64>,
-        ldvsr: *const c_int,
+        ldvsr: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cggesx_64_")]
     pub fn cggesx_(
         jobvsl: *const c_char,
         jobvsr: *const c_char,
         sort: *const c_char,
         selctg: LAPACK_C_SELECT2,
         sense: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        sdim: *mut c_int,
+        ldb: *const lapack_int,
+        sdim: *mut lapack_int,
         alpha: *mut __BindgenComplex<f32>,
         beta: *mut __BindgenComplex<f32>,
         VSL: *mut __BindgenComplex<f32>,
-        ldvsl: *const c_int,
+        ldvsl: *const lapack_int,
         VSR: *mut __BindgenComplex<f32>,
-        ldvsr: *const c_int,
+        ldvsr: *const lapack_int,
         rconde: *mut f32,
         rcondv: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dggesx_64_")]
     pub fn dggesx_(
         jobvsl: *const c_char,
         jobvsr: *const c_char,
         sort: *const c_char,
         selctg: LAPACK_D_SELECT3,
         sense: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
-        sdim: *mut c_int,
+        ldb: *const lapack_int,
+        sdim: *mut lapack_int,
         alphar: *mut f64,
         alphai: *mut f64,
         beta: *mut f64,
         VSL: *mut f64,
-        ldvsl: *const c_int,
+        ldvsl: *const lapack_int,
         VSR: *mut f64,
-        ldvsr: *const c_int,
+        ldvsr: *const lapack_int,
         rconde: *mut f64,
         rcondv: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sggesx_64_")]
     pub fn sggesx_(
         jobvsl: *const c_char,
         jobvsr: *const c_char,
         sort: *const c_char,
         selctg: LAPACK_S_SELECT3,
         sense: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
-        sdim: *mut c_int,
+        ldb: *const lapack_int,
+        sdim: *mut lapack_int,
         alphar: *mut f32,
         alphai: *mut f32,
         beta: *mut f32,
         VSL: *mut f32,
-        ldvsl: *const c_int,
+        ldvsl: *const lapack_int,
         VSR: *mut f32,
-        ldvsr: *const c_int,
+        ldvsr: *const lapack_int,
         rconde: *mut f32,
         rcondv: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zggesx_64_")]
     pub fn zggesx_(
         jobvsl: *const c_char,
         jobvsr: *const c_char,
         sort: *const c_char,
         selctg: LAPACK_Z_SELECT2,
         sense: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        sdim: *mut c_int,
+        ldb: *const lapack_int,
+        sdim: *mut lapack_int,
         alpha: *mut __BindgenComplex<f64>,
         beta: *mut __BindgenComplex<f64>,
         VSL: *mut __BindgenComplex<f64>,
-        ldvsl: *const c_int,
+        ldvsl: *const lapack_int,
         VSR: *mut __BindgenComplex<f64>,
-        ldvsr: *const c_int,
+        ldvsr: *const lapack_int,
         rconde: *mut f64,
         rcondv: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cggev_64_")]
     pub fn cggev_(
         jobvl: *const c_char,
         jobvr: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alpha: *mut __BindgenComplex<f32>,
         beta: *mut __BindgenComplex<f32>,
         VL: *mut __BindgenComplex<f32>,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut __BindgenComplex<f32>,
-        ldvr: *const c_int,
+        ldvr: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dggev_64_")]
     pub fn dggev_(
         jobvl: *const c_char,
         jobvr: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alphar: *mut f64,
         alphai: *mut f64,
         beta: *mut f64,
         VL: *mut f64,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut f64,
-        ldvr: *const c_int,
+        ldvr: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sggev_64_")]
     pub fn sggev_(
         jobvl: *const c_char,
         jobvr: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alphar: *mut f32,
         alphai: *mut f32,
         beta: *mut f32,
         VL: *mut f32,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut f32,
-        ldvr: *const c_int,
+        ldvr: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zggev_64_")]
     pub fn zggev_(
         jobvl: *const c_char,
         jobvr: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alpha: *mut __BindgenComplex<f64>,
         beta: *mut __BindgenComplex<f64>,
         VL: *mut __BindgenComplex<f64>,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut __BindgenComplex<f64>,
-        ldvr: *const c_int,
+        ldvr: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cggev3_64_")]
     pub fn cggev3_(
         jobvl: *const c_char,
         jobvr: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alpha: *mut __BindgenComplex<f32>,
         beta: *mut __BindgenComplex<f32>,
         VL: *mut __BindgenComplex<f32>,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut __BindgenComplex<f32>,
-        ldvr: *const c_int,
+        ldvr: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dggev3_64_")]
     pub fn dggev3_(
         jobvl: *const c_char,
         jobvr: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alphar: *mut f64,
         alphai: *mut f64,
         beta: *mut f64,
         VL: *mut f64,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut f64,
-        ldvr: *const c_int,
+        ldvr: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sggev3_64_")]
     pub fn sggev3_(
         jobvl: *const c_char,
         jobvr: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alphar: *mut f32,
         alphai: *mut f32,
         beta: *mut f32,
         VL: *mut f32,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut f32,
-        ldvr: *const c_int,
+        ldvr: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zggev3_64_")]
     pub fn zggev3_(
         jobvl: *const c_char,
         jobvr: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alpha: *mut __BindgenComplex<f64>,
         beta: *mut __BindgenComplex<f64>,
         VL: *mut __BindgenComplex<f64>,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut __BindgenComplex<f64>,
-        ldvr: *const c_int,
+        ldvr: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cggevx_64_")]
     pub fn cggevx_(
         balanc: *const c_char,
         jobvl: *const c_char,
         jobvr: *const c_char,
         sense: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alpha: *mut __BindgenComplex<f32>,
         beta: *mut __BindgenComplex<f32>,
         VL: *mut __BindgenComplex<f32>,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut __BindgenComplex<f32>,
-        ldvr: *const c_int,
-        ilo: *mut c_int,
-        ihi: *mut c_int,
+        ldvr: *const lapack_int,
+        ilo: *mut lapack_int,
+        ihi: *mut lapack_int,
         lscale: *mut f32,
         rscale: *mut f32,
         abnrm: *mut f32,
@@ -5244,33 +5547,34 @@ extern "C" {
         rconde: *mut f32,
         rcondv: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        iwork: *mut c_int,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dggevx_64_")]
     pub fn dggevx_(
         balanc: *const c_char,
         jobvl: *const c_char,
         jobvr: *const c_char,
         sense: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alphar: *mut f64,
         alphai: *mut f64,
         beta: *mut f64,
         VL: *mut f64,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut f64,
-        ldvr: *const c_int,
-        ilo: *mut c_int,
-        ihi: *mut c_int,
+        ldvr: *const lapack_int,
+        ilo: *mut lapack_int,
+        ihi: *mut lapack_int,
         lscale: *mut f64,
         rscale: *mut f64,
         abnrm: *mut f64,
@@ -5278,32 +5582,33 @@ extern "C" {
         rconde: *mut f64,
         rcondv: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sggevx_64_")]
     pub fn sggevx_(
         balanc: *const c_char,
         jobvl: *const c_char,
         jobvr: *const c_char,
         sense: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alphar: *mut f32,
         alphai: *mut f32,
         beta: *mut f32,
         VL: *mut f32,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut f32,
-        ldvr: *const c_int,
-        ilo: *mut c_int,
-        ihi: *mut c_int,
+        ldvr: *const lapack_int,
+        ilo: *mut lapack_int,
+        ihi: *mut lapack_int,
         lscale: *mut f32,
         rscale: *mut f32,
         abnrm: *mut f32,
@@ -5311,31 +5616,32 @@ extern "C" {
         rconde: *mut f32,
         rcondv: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zggevx_64_")]
     pub fn zggevx_(
         balanc: *const c_char,
         jobvl: *const c_char,
         jobvr: *const c_char,
         sense: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alpha: *mut __BindgenComplex<f64>,
         beta: *mut __BindgenComplex<f64>,
         VL: *mut __BindgenComplex<f64>,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut __BindgenComplex<f64>,
-        ldvr: *const c_int,
-        ilo: *mut c_int,
-        ihi: *mut c_int,
+        ldvr: *const lapack_int,
+        ilo: *mut lapack_int,
+        ihi: *mut lapack_int,
         lscale: *mut f64,
         rscale: *mut f64,
         abnrm: *mut f64,
@@ -5343,952 +5649,1005 @@ extern "C" {
         rconde: *mut f64,
         rcondv: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        iwork: *mut c_int,
-        BWORK: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        BWORK: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cggglm_64_")]
     pub fn cggglm_(
-        n: *const c_int,
-        m: *const c_int,
-        p: *const c_int,
+        n: *const lapack_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         D: *mut __BindgenComplex<f32>,
         X: *mut __BindgenComplex<f32>,
         Y: *mut __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dggglm_64_")]
     pub fn dggglm_(
-        n: *const c_int,
-        m: *const c_int,
-        p: *const c_int,
+        n: *const lapack_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         D: *mut f64,
         X: *mut f64,
         Y: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sggglm_64_")]
     pub fn sggglm_(
-        n: *const c_int,
-        m: *const c_int,
-        p: *const c_int,
+        n: *const lapack_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         D: *mut f32,
         X: *mut f32,
         Y: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zggglm_64_")]
     pub fn zggglm_(
-        n: *const c_int,
-        m: *const c_int,
-        p: *const c_int,
+        n: *const lapack_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         D: *mut __BindgenComplex<f64>,
         X: *mut __BindgenComplex<f64>,
         Y: *mut __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgghd3_64_")]
     pub fn cgghd3_(
         compq: *const c_char,
         compz: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         Q: *mut __BindgenComplex<f32>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgghd3_64_")]
     pub fn dgghd3_(
         compq: *const c_char,
         compz: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         Q: *mut f64,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgghd3_64_")]
     pub fn sgghd3_(
         compq: *const c_char,
         compz: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         Q: *mut f32,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgghd3_64_")]
     pub fn zgghd3_(
         compq: *const c_char,
         compz: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         Q: *mut __BindgenComplex<f64>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgghrd_64_")]
     pub fn cgghrd_(
         compq: *const c_char,
         compz: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         Q: *mut __BindgenComplex<f32>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
-        info: *mut c_int,
+        ldz: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgghrd_64_")]
     pub fn dgghrd_(
         compq: *const c_char,
         compz: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         Q: *mut f64,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         Z: *mut f64,
-        ldz: *const c_int,
-        info: *mut c_int,
+        ldz: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgghrd_64_")]
     pub fn sgghrd_(
         compq: *const c_char,
         compz: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         Q: *mut f32,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         Z: *mut f32,
-        ldz: *const c_int,
-        info: *mut c_int,
+        ldz: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgghrd_64_")]
     pub fn zgghrd_(
         compq: *const c_char,
         compz: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         Q: *mut __BindgenComplex<f64>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
-        info: *mut c_int,
+        ldz: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgglse_64_")]
     pub fn cgglse_(
-        m: *const c_int,
-        n: *const c_int,
-        p: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        p: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         C: *mut __BindgenComplex<f32>,
         D: *mut __BindgenComplex<f32>,
         X: *mut __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgglse_64_")]
     pub fn dgglse_(
-        m: *const c_int,
-        n: *const c_int,
-        p: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        p: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         C: *mut f64,
         D: *mut f64,
         X: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgglse_64_")]
     pub fn sgglse_(
-        m: *const c_int,
-        n: *const c_int,
-        p: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        p: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         C: *mut f32,
         D: *mut f32,
         X: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgglse_64_")]
     pub fn zgglse_(
-        m: *const c_int,
-        n: *const c_int,
-        p: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        p: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         C: *mut __BindgenComplex<f64>,
         D: *mut __BindgenComplex<f64>,
         X: *mut __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cggqrf_64_")]
     pub fn cggqrf_(
-        n: *const c_int,
-        m: *const c_int,
-        p: *const c_int,
+        n: *const lapack_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         taua: *mut __BindgenComplex<f32>,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         taub: *mut __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dggqrf_64_")]
     pub fn dggqrf_(
-        n: *const c_int,
-        m: *const c_int,
-        p: *const c_int,
+        n: *const lapack_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         taua: *mut f64,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         taub: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sggqrf_64_")]
     pub fn sggqrf_(
-        n: *const c_int,
-        m: *const c_int,
-        p: *const c_int,
+        n: *const lapack_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         taua: *mut f32,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         taub: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zggqrf_64_")]
     pub fn zggqrf_(
-        n: *const c_int,
-        m: *const c_int,
-        p: *const c_int,
+        n: *const lapack_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         taua: *mut __BindgenComplex<f64>,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         taub: *mut __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cggrqf_64_")]
     pub fn cggrqf_(
-        m: *const c_int,
-        p: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         taua: *mut __BindgenComplex<f32>,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         taub: *mut __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dggrqf_64_")]
     pub fn dggrqf_(
-        m: *const c_int,
-        p: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         taua: *mut f64,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         taub: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sggrqf_64_")]
     pub fn sggrqf_(
-        m: *const c_int,
-        p: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         taua: *mut f32,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         taub: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zggrqf_64_")]
     pub fn zggrqf_(
-        m: *const c_int,
-        p: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         taua: *mut __BindgenComplex<f64>,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         taub: *mut __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[deprecated(note = "use sggsvd3_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sggsvd_64_")]
     pub fn sggsvd_(
         jobu: *const c_char,
         jobv: *const c_char,
         jobq: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        p: *const c_int,
-        k: *mut c_int,
-        l: *mut c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        p: *const lapack_int,
+        k: *mut lapack_int,
+        l: *mut lapack_int,
         a: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         b: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alpha: *mut f32,
         beta: *mut f32,
         u: *mut f32,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         v: *mut f32,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         q: *mut f32,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
-    ) -> c_int;
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
+    ) -> lapack_int;
 }
 extern "C" {
+    #[deprecated(note = "use dggsvd3_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dggsvd_64_")]
     pub fn dggsvd_(
         jobu: *const c_char,
         jobv: *const c_char,
         jobq: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        p: *const c_int,
-        k: *mut c_int,
-        l: *mut c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        p: *const lapack_int,
+        k: *mut lapack_int,
+        l: *mut lapack_int,
         a: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         b: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alpha: *mut f64,
         beta: *mut f64,
         u: *mut f64,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         v: *mut f64,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         q: *mut f64,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
-    ) -> c_int;
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
+    ) -> lapack_int;
 }
 extern "C" {
+    #[deprecated(note = "use cggsvd3_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cggsvd_64_")]
     pub fn cggsvd_(
         jobu: *const c_char,
         jobv: *const c_char,
         jobq: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        p: *const c_int,
-        k: *mut c_int,
-        l: *mut c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        p: *const lapack_int,
+        k: *mut lapack_int,
+        l: *mut lapack_int,
         a: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         b: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alpha: *mut f32,
         beta: *mut f32,
         u: *mut __BindgenComplex<f32>,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         v: *mut __BindgenComplex<f32>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         q: *mut __BindgenComplex<f32>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
-    ) -> c_int;
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
+    ) -> lapack_int;
 }
 extern "C" {
+    #[deprecated(note = "use zggsvd3_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zggsvd_64_")]
     pub fn zggsvd_(
         jobu: *const c_char,
         jobv: *const c_char,
         jobq: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        p: *const c_int,
-        k: *mut c_int,
-        l: *mut c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        p: *const lapack_int,
+        k: *mut lapack_int,
+        l: *mut lapack_int,
         a: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         b: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alpha: *mut f64,
         beta: *mut f64,
         u: *mut __BindgenComplex<f64>,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         v: *mut __BindgenComplex<f64>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         q: *mut __BindgenComplex<f64>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
-    ) -> c_int;
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
+    ) -> lapack_int;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cggsvd3_64_")]
     pub fn cggsvd3_(
         jobu: *const c_char,
         jobv: *const c_char,
         jobq: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        p: *const c_int,
-        k: *mut c_int,
-        l: *mut c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        p: *const lapack_int,
+        k: *mut lapack_int,
+        l: *mut lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alpha: *mut f32,
         beta: *mut f32,
         U: *mut __BindgenComplex<f32>,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         V: *mut __BindgenComplex<f32>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         Q: *mut __BindgenComplex<f32>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dggsvd3_64_")]
     pub fn dggsvd3_(
         jobu: *const c_char,
         jobv: *const c_char,
         jobq: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        p: *const c_int,
-        k: *mut c_int,
-        l: *mut c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        p: *const lapack_int,
+        k: *mut lapack_int,
+        l: *mut lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alpha: *mut f64,
         beta: *mut f64,
         U: *mut f64,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         V: *mut f64,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         Q: *mut f64,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sggsvd3_64_")]
     pub fn sggsvd3_(
         jobu: *const c_char,
         jobv: *const c_char,
         jobq: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        p: *const c_int,
-        k: *mut c_int,
-        l: *mut c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        p: *const lapack_int,
+        k: *mut lapack_int,
+        l: *mut lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alpha: *mut f32,
         beta: *mut f32,
         U: *mut f32,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         V: *mut f32,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         Q: *mut f32,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zggsvd3_64_")]
     pub fn zggsvd3_(
         jobu: *const c_char,
         jobv: *const c_char,
         jobq: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        p: *const c_int,
-        k: *mut c_int,
-        l: *mut c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        p: *const lapack_int,
+        k: *mut lapack_int,
+        l: *mut lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alpha: *mut f64,
         beta: *mut f64,
         U: *mut __BindgenComplex<f64>,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         V: *mut __BindgenComplex<f64>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         Q: *mut __BindgenComplex<f64>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[deprecated(note = "use sggsvp3_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sggsvp_64_")]
     pub fn sggsvp_(
         jobu: *const c_char,
         jobv: *const c_char,
         jobq: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        n: *const lapack_int,
         a: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         b: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         tola: *mut f32,
         tolb: *mut f32,
-        k: *mut c_int,
-        l: *mut c_int,
+        k: *mut lapack_int,
+        l: *mut lapack_int,
         u: *mut f32,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         v: *mut f32,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         q: *mut f32,
-        ldq: *const c_int,
-        iwork: *mut c_int,
+        ldq: *const lapack_int,
+        iwork: *mut lapack_int,
         tau: *mut f32,
         work: *mut f32,
-        info: *mut c_int,
-    ) -> c_int;
+        info: *mut lapack_int,
+    ) -> lapack_int;
 }
 extern "C" {
+    #[deprecated(note = "use dggsvp3_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dggsvp_64_")]
     pub fn dggsvp_(
         jobu: *const c_char,
         jobv: *const c_char,
         jobq: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        n: *const lapack_int,
         a: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         b: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         tola: *mut f64,
         tolb: *mut f64,
-        k: *mut c_int,
-        l: *mut c_int,
+        k: *mut lapack_int,
+        l: *mut lapack_int,
         u: *mut f64,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         v: *mut f64,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         q: *mut f64,
-        ldq: *const c_int,
-        iwork: *mut c_int,
+        ldq: *const lapack_int,
+        iwork: *mut lapack_int,
         tau: *mut f64,
         work: *mut f64,
-        info: *mut c_int,
-    ) -> c_int;
+        info: *mut lapack_int,
+    ) -> lapack_int;
 }
 extern "C" {
+    #[deprecated(note = "use cggsvp3_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cggsvp_64_")]
     pub fn cggsvp_(
         jobu: *const c_char,
         jobv: *const c_char,
         jobq: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        n: *const lapack_int,
         a: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         b: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         tola: *mut f32,
         tolb: *mut f32,
-        k: *mut c_int,
-        l: *mut c_int,
+        k: *mut lapack_int,
+        l: *mut lapack_int,
         u: *mut __BindgenComplex<f32>,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         v: *mut __BindgenComplex<f32>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         q: *mut __BindgenComplex<f32>,
-        ldq: *const c_int,
-        iwork: *mut c_int,
+        ldq: *const lapack_int,
+        iwork: *mut lapack_int,
         rwork: *mut f32,
         tau: *mut __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
-    ) -> c_int;
+        info: *mut lapack_int,
+    ) -> lapack_int;
 }
 extern "C" {
+    #[deprecated(note = "use zggsvp3_ instead")]
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zggsvp_64_")]
     pub fn zggsvp_(
         jobu: *const c_char,
         jobv: *const c_char,
         jobq: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        n: *const lapack_int,
         a: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         b: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         tola: *mut f64,
         tolb: *mut f64,
-        k: *mut c_int,
-        l: *mut c_int,
+        k: *mut lapack_int,
+        l: *mut lapack_int,
         u: *mut __BindgenComplex<f64>,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         v: *mut __BindgenComplex<f64>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         q: *mut __BindgenComplex<f64>,
-        ldq: *const c_int,
-        iwork: *mut c_int,
+        ldq: *const lapack_int,
+        iwork: *mut lapack_int,
         rwork: *mut f64,
         tau: *mut __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
-    ) -> c_int;
+        info: *mut lapack_int,
+    ) -> lapack_int;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cggsvp3_64_")]
     pub fn cggsvp3_(
         jobu: *const c_char,
         jobv: *const c_char,
         jobq: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         tola: *const f32,
         tolb: *const f32,
-        k: *mut c_int,
-        l: *mut c_int,
+        k: *mut lapack_int,
+        l: *mut lapack_int,
         U: *mut __BindgenComplex<f32>,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         V: *mut __BindgenComplex<f32>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         Q: *mut __BindgenComplex<f32>,
-        ldq: *const c_int,
-        iwork: *mut c_int,
+        ldq: *const lapack_int,
+        iwork: *mut lapack_int,
         rwork: *mut f32,
         tau: *mut __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dggsvp3_64_")]
     pub fn dggsvp3_(
         jobu: *const c_char,
         jobv: *const c_char,
         jobq: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         tola: *const f64,
         tolb: *const f64,
-        k: *mut c_int,
-        l: *mut c_int,
+        k: *mut lapack_int,
+        l: *mut lapack_int,
         U: *mut f64,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         V: *mut f64,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         Q: *mut f64,
-        ldq: *const c_int,
-        iwork: *mut c_int,
+        ldq: *const lapack_int,
+        iwork: *mut lapack_int,
         tau: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sggsvp3_64_")]
     pub fn sggsvp3_(
         jobu: *const c_char,
         jobv: *const c_char,
         jobq: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         tola: *const f32,
         tolb: *const f32,
-        k: *mut c_int,
-        l: *mut c_int,
+        k: *mut lapack_int,
+        l: *mut lapack_int,
         U: *mut f32,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         V: *mut f32,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         Q: *mut f32,
-        ldq: *const c_int,
-        iwork: *mut c_int,
+        ldq: *const lapack_int,
+        iwork: *mut lapack_int,
         tau: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zggsvp3_64_")]
     pub fn zggsvp3_(
         jobu: *const c_char,
         jobv: *const c_char,
         jobq: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         tola: *const f64,
         tolb: *const f64,
-        k: *mut c_int,
-        l: *mut c_int,
+        k: *mut lapack_int,
+        l: *mut lapack_int,
         U: *mut __BindgenComplex<f64>,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         V: *mut __BindgenComplex<f64>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         Q: *mut __BindgenComplex<f64>,
-        ldq: *const c_int,
-        iwork: *mut c_int,
+        ldq: *const lapack_int,
+        iwork: *mut lapack_int,
         rwork: *mut f64,
         tau: *mut __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgtcon_64_")]
     pub fn cgtcon_(
         norm: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         DL: *const __BindgenComplex<f32>,
         D: *const __BindgenComplex<f32>,
         DU: *const __BindgenComplex<f32>,
         DU2: *const __BindgenComplex<f32>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         anorm: *const f32,
         rcond: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgtcon_64_")]
     pub fn dgtcon_(
         norm: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         DL: *const f64,
         D: *const f64,
         DU: *const f64,
         DU2: *const f64,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         anorm: *const f64,
         rcond: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgtcon_64_")]
     pub fn sgtcon_(
         norm: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         DL: *const f32,
         D: *const f32,
         DU: *const f32,
         DU2: *const f32,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         anorm: *const f32,
         rcond: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgtcon_64_")]
     pub fn zgtcon_(
         norm: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         DL: *const __BindgenComplex<f64>,
         D: *const __BindgenComplex<f64>,
         DU: *const __BindgenComplex<f64>,
         DU2: *const __BindgenComplex<f64>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         anorm: *const f64,
         rcond: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgtrfs_64_")]
     pub fn cgtrfs_(
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         DL: *const __BindgenComplex<f32>,
         D: *const __BindgenComplex<f32>,
         DU: *const __BindgenComplex<f32>,
@@ -6296,23 +6655,24 @@ extern "C" {
         DF: *const __BindgenComplex<f32>,
         DUF: *const __BindgenComplex<f32>,
         DU2: *const __BindgenComplex<f32>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgtrfs_64_")]
     pub fn dgtrfs_(
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         DL: *const f64,
         D: *const f64,
         DU: *const f64,
@@ -6320,23 +6680,24 @@ extern "C" {
         DF: *const f64,
         DUF: *const f64,
         DU2: *const f64,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgtrfs_64_")]
     pub fn sgtrfs_(
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         DL: *const f32,
         D: *const f32,
         DU: *const f32,
@@ -6344,23 +6705,24 @@ extern "C" {
         DF: *const f32,
         DUF: *const f32,
         DU2: *const f32,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgtrfs_64_")]
     pub fn zgtrfs_(
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         DL: *const __BindgenComplex<f64>,
         D: *const __BindgenComplex<f64>,
         DU: *const __BindgenComplex<f64>,
@@ -6368,72 +6730,77 @@ extern "C" {
         DF: *const __BindgenComplex<f64>,
         DUF: *const __BindgenComplex<f64>,
         DU2: *const __BindgenComplex<f64>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgtsv_64_")]
     pub fn cgtsv_(
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         DL: *mut __BindgenComplex<f32>,
         D: *mut __BindgenComplex<f32>,
         DU: *mut __BindgenComplex<f32>,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgtsv_64_")]
     pub fn dgtsv_(
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         DL: *mut f64,
         D: *mut f64,
         DU: *mut f64,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgtsv_64_")]
     pub fn sgtsv_(
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         DL: *mut f32,
         D: *mut f32,
         DU: *mut f32,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgtsv_64_")]
     pub fn zgtsv_(
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         DL: *mut __BindgenComplex<f64>,
         D: *mut __BindgenComplex<f64>,
         DU: *mut __BindgenComplex<f64>,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgtsvx_64_")]
     pub fn cgtsvx_(
         fact: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         DL: *const __BindgenComplex<f32>,
         D: *const __BindgenComplex<f32>,
         DU: *const __BindgenComplex<f32>,
@@ -6441,25 +6808,26 @@ extern "C" {
         DF: *mut __BindgenComplex<f32>,
         DUF: *mut __BindgenComplex<f32>,
         DU2: *mut __BindgenComplex<f32>,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgtsvx_64_")]
     pub fn dgtsvx_(
         fact: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         DL: *const f64,
         D: *const f64,
         DU: *const f64,
@@ -6467,25 +6835,26 @@ extern "C" {
         DF: *mut f64,
         DUF: *mut f64,
         DU2: *mut f64,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgtsvx_64_")]
     pub fn sgtsvx_(
         fact: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         DL: *const f32,
         D: *const f32,
         DU: *const f32,
@@ -6493,25 +6862,26 @@ extern "C" {
         DF: *mut f32,
         DUF: *mut f32,
         DU2: *mut f32,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgtsvx_64_")]
     pub fn zgtsvx_(
         fact: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         DL: *const __BindgenComplex<f64>,
         D: *const __BindgenComplex<f64>,
         DU: *const __BindgenComplex<f64>,
@@ -6519,4388 +6889,4716 @@ extern "C" {
         DF: *mut __BindgenComplex<f64>,
         DUF: *mut __BindgenComplex<f64>,
         DU2: *mut __BindgenComplex<f64>,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgttrf_64_")]
     pub fn cgttrf_(
-        n: *const c_int,
+        n: *const lapack_int,
         DL: *mut __BindgenComplex<f32>,
         D: *mut __BindgenComplex<f32>,
         DU: *mut __BindgenComplex<f32>,
         DU2: *mut __BindgenComplex<f32>,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgttrf_64_")]
     pub fn dgttrf_(
-        n: *const c_int,
+        n: *const lapack_int,
         DL: *mut f64,
         D: *mut f64,
         DU: *mut f64,
         DU2: *mut f64,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgttrf_64_")]
     pub fn sgttrf_(
-        n: *const c_int,
+        n: *const lapack_int,
         DL: *mut f32,
         D: *mut f32,
         DU: *mut f32,
         DU2: *mut f32,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgttrf_64_")]
     pub fn zgttrf_(
-        n: *const c_int,
+        n: *const lapack_int,
         DL: *mut __BindgenComplex<f64>,
         D: *mut __BindgenComplex<f64>,
         DU: *mut __BindgenComplex<f64>,
         DU2: *mut __BindgenComplex<f64>,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cgttrs_64_")]
     pub fn cgttrs_(
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         DL: *const __BindgenComplex<f32>,
         D: *const __BindgenComplex<f32>,
         DU: *const __BindgenComplex<f32>,
         DU2: *const __BindgenComplex<f32>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dgttrs_64_")]
     pub fn dgttrs_(
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         DL: *const f64,
         D: *const f64,
         DU: *const f64,
         DU2: *const f64,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sgttrs_64_")]
     pub fn sgttrs_(
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         DL: *const f32,
         D: *const f32,
         DU: *const f32,
         DU2: *const f32,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zgttrs_64_")]
     pub fn zgttrs_(
         trans: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         DL: *const __BindgenComplex<f64>,
         D: *const __BindgenComplex<f64>,
         DU: *const __BindgenComplex<f64>,
         DU2: *const __BindgenComplex<f64>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chbev_64_")]
     pub fn chbev_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         W: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhbev_64_")]
     pub fn zhbev_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         W: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chbev_2stage_64_")]
     pub fn chbev_2stage_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         W: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhbev_2stage_64_")]
     pub fn zhbev_2stage_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         W: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chbevd_64_")]
     pub fn chbevd_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         W: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhbevd_64_")]
     pub fn zhbevd_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         W: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chbevd_2stage_64_")]
     pub fn chbevd_2stage_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         W: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhbevd_2stage_64_")]
     pub fn zhbevd_2stage_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         W: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chbevx_64_")]
     pub fn chbevx_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         Q: *mut __BindgenComplex<f32>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhbevx_64_")]
     pub fn zhbevx_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         Q: *mut __BindgenComplex<f64>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chbevx_2stage_64_")]
     pub fn chbevx_2stage_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         Q: *mut __BindgenComplex<f32>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhbevx_2stage_64_")]
     pub fn zhbevx_2stage_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         Q: *mut __BindgenComplex<f64>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chbgst_64_")]
     pub fn chbgst_(
         vect: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        ka: *const c_int,
-        kb: *const c_int,
+        n: *const lapack_int,
+        ka: *const lapack_int,
+        kb: *const lapack_int,
         AB: *mut __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         BB: *const __BindgenComplex<f32>,
-        ldbb: *const c_int,
+        ldbb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhbgst_64_")]
     pub fn zhbgst_(
         vect: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        ka: *const c_int,
-        kb: *const c_int,
+        n: *const lapack_int,
+        ka: *const lapack_int,
+        kb: *const lapack_int,
         AB: *mut __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         BB: *const __BindgenComplex<f64>,
-        ldbb: *const c_int,
+        ldbb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chbgv_64_")]
     pub fn chbgv_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        ka: *const c_int,
-        kb: *const c_int,
+        n: *const lapack_int,
+        ka: *const lapack_int,
+        kb: *const lapack_int,
         AB: *mut __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         BB: *mut __BindgenComplex<f32>,
-        ldbb: *const c_int,
+        ldbb: *const lapack_int,
         W: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhbgv_64_")]
     pub fn zhbgv_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        ka: *const c_int,
-        kb: *const c_int,
+        n: *const lapack_int,
+        ka: *const lapack_int,
+        kb: *const lapack_int,
         AB: *mut __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         BB: *mut __BindgenComplex<f64>,
-        ldbb: *const c_int,
+        ldbb: *const lapack_int,
         W: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chbgvd_64_")]
     pub fn chbgvd_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        ka: *const c_int,
-        kb: *const c_int,
+        n: *const lapack_int,
+        ka: *const lapack_int,
+        kb: *const lapack_int,
         AB: *mut __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         BB: *mut __BindgenComplex<f32>,
-        ldbb: *const c_int,
+        ldbb: *const lapack_int,
         W: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhbgvd_64_")]
     pub fn zhbgvd_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        ka: *const c_int,
-        kb: *const c_int,
+        n: *const lapack_int,
+        ka: *const lapack_int,
+        kb: *const lapack_int,
         AB: *mut __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         BB: *mut __BindgenComplex<f64>,
-        ldbb: *const c_int,
+        ldbb: *const lapack_int,
         W: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chbgvx_64_")]
     pub fn chbgvx_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        ka: *const c_int,
-        kb: *const c_int,
+        n: *const lapack_int,
+        ka: *const lapack_int,
+        kb: *const lapack_int,
         AB: *mut __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         BB: *mut __BindgenComplex<f32>,
-        ldbb: *const c_int,
+        ldbb: *const lapack_int,
         Q: *mut __BindgenComplex<f32>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhbgvx_64_")]
     pub fn zhbgvx_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        ka: *const c_int,
-        kb: *const c_int,
+        n: *const lapack_int,
+        ka: *const lapack_int,
+        kb: *const lapack_int,
         AB: *mut __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         BB: *mut __BindgenComplex<f64>,
-        ldbb: *const c_int,
+        ldbb: *const lapack_int,
         Q: *mut __BindgenComplex<f64>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chbtrd_64_")]
     pub fn chbtrd_(
         vect: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         Q: *mut __BindgenComplex<f32>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhbtrd_64_")]
     pub fn zhbtrd_(
         vect: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         Q: *mut __BindgenComplex<f64>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "checon_64_")]
     pub fn checon_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         anorm: *const f32,
         rcond: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhecon_64_")]
     pub fn zhecon_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         anorm: *const f64,
         rcond: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "checon_3_64_")]
     pub fn checon_3_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *const __BindgenComplex<f32>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         anorm: *const f32,
         rcond: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhecon_3_64_")]
     pub fn zhecon_3_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *const __BindgenComplex<f64>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         anorm: *const f64,
         rcond: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cheequb_64_")]
     pub fn cheequb_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f32,
         scond: *mut f32,
         amax: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zheequb_64_")]
     pub fn zheequb_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f64,
         scond: *mut f64,
         amax: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cheev_64_")]
     pub fn cheev_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         W: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zheev_64_")]
     pub fn zheev_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         W: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cheev_2stage_64_")]
     pub fn cheev_2stage_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         W: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zheev_2stage_64_")]
     pub fn zheev_2stage_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         W: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cheevd_64_")]
     pub fn cheevd_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         W: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zheevd_64_")]
     pub fn zheevd_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         W: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cheevd_2stage_64_")]
     pub fn cheevd_2stage_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         W: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zheevd_2stage_64_")]
     pub fn zheevd_2stage_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         W: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cheevr_64_")]
     pub fn cheevr_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
-        ISUPPZ: *mut c_int,
+        ldz: *const lapack_int,
+        ISUPPZ: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zheevr_64_")]
     pub fn zheevr_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
-        ISUPPZ: *mut c_int,
+        ldz: *const lapack_int,
+        ISUPPZ: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cheevr_2stage_64_")]
     pub fn cheevr_2stage_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
-        ISUPPZ: *mut c_int,
+        ldz: *const lapack_int,
+        ISUPPZ: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zheevr_2stage_64_")]
     pub fn zheevr_2stage_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
-        ISUPPZ: *mut c_int,
+        ldz: *const lapack_int,
+        ISUPPZ: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cheevx_64_")]
     pub fn cheevx_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zheevx_64_")]
     pub fn zheevx_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cheevx_2stage_64_")]
     pub fn cheevx_2stage_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zheevx_2stage_64_")]
     pub fn zheevx_2stage_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chegst_64_")]
     pub fn chegst_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhegst_64_")]
     pub fn zhegst_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chegv_64_")]
     pub fn chegv_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         W: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhegv_64_")]
     pub fn zhegv_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         W: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chegv_2stage_64_")]
     pub fn chegv_2stage_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         W: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhegv_2stage_64_")]
     pub fn zhegv_2stage_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         W: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chegvd_64_")]
     pub fn chegvd_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         W: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhegvd_64_")]
     pub fn zhegvd_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         W: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chegvx_64_")]
     pub fn chegvx_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhegvx_64_")]
     pub fn zhegvx_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cherfs_64_")]
     pub fn cherfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const __BindgenComplex<f32>,
-        ldaf: *const c_int,
-        ipiv: *const c_int,
+        ldaf: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zherfs_64_")]
     pub fn zherfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const __BindgenComplex<f64>,
-        ldaf: *const c_int,
-        ipiv: *const c_int,
+        ldaf: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cherfsx_64_")]
     pub fn cherfsx_(
         uplo: *const c_char,
         equed: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const __BindgenComplex<f32>,
-        ldaf: *const c_int,
-        ipiv: *const c_int,
+        ldaf: *const lapack_int,
+        ipiv: *const lapack_int,
         S: *mut f32,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         berr: *mut f32,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f32,
         err_bnds_comp: *mut f32,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zherfsx_64_")]
     pub fn zherfsx_(
         uplo: *const c_char,
         equed: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const __BindgenComplex<f64>,
-        ldaf: *const c_int,
-        ipiv: *const c_int,
+        ldaf: *const lapack_int,
+        ipiv: *const lapack_int,
         S: *mut f64,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         berr: *mut f64,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f64,
         err_bnds_comp: *mut f64,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chesv_64_")]
     pub fn chesv_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhesv_64_")]
     pub fn zhesv_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chesv_aa_64_")]
     pub fn chesv_aa_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhesv_aa_64_")]
     pub fn zhesv_aa_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chesv_aa_2stage_64_")]
     pub fn chesv_aa_2stage_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         TB: *mut __BindgenComplex<f32>,
-        ltb: *const c_int,
-        ipiv: *mut c_int,
-        ipiv2: *mut c_int,
+        ltb: *const lapack_int,
+        ipiv: *mut lapack_int,
+        ipiv2: *mut lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhesv_aa_2stage_64_")]
     pub fn zhesv_aa_2stage_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         TB: *mut __BindgenComplex<f64>,
-        ltb: *const c_int,
-        ipiv: *mut c_int,
-        ipiv2: *mut c_int,
+        ltb: *const lapack_int,
+        ipiv: *mut lapack_int,
+        ipiv2: *mut lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chesv_rk_64_")]
     pub fn chesv_rk_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *mut __BindgenComplex<f32>,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhesv_rk_64_")]
     pub fn zhesv_rk_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *mut __BindgenComplex<f64>,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chesv_rook_64_")]
     pub fn chesv_rook_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhesv_rook_64_")]
     pub fn zhesv_rook_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chesvx_64_")]
     pub fn chesvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut __BindgenComplex<f32>,
-        ldaf: *const c_int,
-        ipiv: *mut c_int,
+        ldaf: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhesvx_64_")]
     pub fn zhesvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut __BindgenComplex<f64>,
-        ldaf: *const c_int,
-        ipiv: *mut c_int,
+        ldaf: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chesvxx_64_")]
     pub fn chesvxx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut __BindgenComplex<f32>,
-        ldaf: *const c_int,
-        ipiv: *mut c_int,
+        ldaf: *const lapack_int,
+        ipiv: *mut lapack_int,
         equed: *mut c_char,
         S: *mut f32,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         rpvgrw: *mut f32,
         berr: *mut f32,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f32,
         err_bnds_comp: *mut f32,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhesvxx_64_")]
     pub fn zhesvxx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut __BindgenComplex<f64>,
-        ldaf: *const c_int,
-        ipiv: *mut c_int,
+        ldaf: *const lapack_int,
+        ipiv: *mut lapack_int,
         equed: *mut c_char,
         S: *mut f64,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         rpvgrw: *mut f64,
         berr: *mut f64,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f64,
         err_bnds_comp: *mut f64,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cheswapr_64_")]
     pub fn cheswapr_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        i1: *const c_int,
-        i2: *const c_int,
+        lda: *const lapack_int,
+        i1: *const lapack_int,
+        i2: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zheswapr_64_")]
     pub fn zheswapr_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        i1: *const c_int,
-        i2: *const c_int,
+        lda: *const lapack_int,
+        i1: *const lapack_int,
+        i2: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chetrd_64_")]
     pub fn chetrd_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         tau: *mut __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhetrd_64_")]
     pub fn zhetrd_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         tau: *mut __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chetrd_2stage_64_")]
     pub fn chetrd_2stage_(
         vect: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         tau: *mut __BindgenComplex<f32>,
         HOUS2: *mut __BindgenComplex<f32>,
-        lhous2: *const c_int,
+        lhous2: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhetrd_2stage_64_")]
     pub fn zhetrd_2stage_(
         vect: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         tau: *mut __BindgenComplex<f64>,
         HOUS2: *mut __BindgenComplex<f64>,
-        lhous2: *const c_int,
+        lhous2: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chetrf_64_")]
     pub fn chetrf_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhetrf_64_")]
     pub fn zhetrf_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chetrf_aa_64_")]
     pub fn chetrf_aa_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhetrf_aa_64_")]
     pub fn zhetrf_aa_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chetrf_aa_2stage_64_")]
     pub fn chetrf_aa_2stage_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         TB: *mut __BindgenComplex<f32>,
-        ltb: *const c_int,
-        ipiv: *mut c_int,
-        ipiv2: *mut c_int,
+        ltb: *const lapack_int,
+        ipiv: *mut lapack_int,
+        ipiv2: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhetrf_aa_2stage_64_")]
     pub fn zhetrf_aa_2stage_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         TB: *mut __BindgenComplex<f64>,
-        ltb: *const c_int,
-        ipiv: *mut c_int,
-        ipiv2: *mut c_int,
+        ltb: *const lapack_int,
+        ipiv: *mut lapack_int,
+        ipiv2: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chetrf_rk_64_")]
     pub fn chetrf_rk_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *mut __BindgenComplex<f32>,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhetrf_rk_64_")]
     pub fn zhetrf_rk_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *mut __BindgenComplex<f64>,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chetrf_rook_64_")]
     pub fn chetrf_rook_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhetrf_rook_64_")]
     pub fn zhetrf_rook_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chetri_64_")]
     pub fn chetri_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhetri_64_")]
     pub fn zhetri_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chetri2_64_")]
     pub fn chetri2_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhetri2_64_")]
     pub fn zhetri2_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chetri2x_64_")]
     pub fn chetri2x_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        nb: *const c_int,
-        info: *mut c_int,
+        nb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhetri2x_64_")]
     pub fn zhetri2x_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        nb: *const c_int,
-        info: *mut c_int,
+        nb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chetri_3_64_")]
     pub fn chetri_3_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *const __BindgenComplex<f32>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhetri_3_64_")]
     pub fn zhetri_3_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *const __BindgenComplex<f64>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chetrs_64_")]
     pub fn chetrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhetrs_64_")]
     pub fn zhetrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chetrs2_64_")]
     pub fn chetrs2_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhetrs2_64_")]
     pub fn zhetrs2_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chetrs_3_64_")]
     pub fn chetrs_3_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *const __BindgenComplex<f32>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhetrs_3_64_")]
     pub fn zhetrs_3_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *const __BindgenComplex<f64>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chetrs_aa_64_")]
     pub fn chetrs_aa_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhetrs_aa_64_")]
     pub fn zhetrs_aa_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chetrs_aa_2stage_64_")]
     pub fn chetrs_aa_2stage_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         TB: *mut __BindgenComplex<f32>,
-        ltb: *const c_int,
-        ipiv: *const c_int,
-        ipiv2: *const c_int,
+        ltb: *const lapack_int,
+        ipiv: *const lapack_int,
+        ipiv2: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhetrs_aa_2stage_64_")]
     pub fn zhetrs_aa_2stage_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         TB: *mut __BindgenComplex<f64>,
-        ltb: *const c_int,
-        ipiv: *const c_int,
-        ipiv2: *const c_int,
+        ltb: *const lapack_int,
+        ipiv: *const lapack_int,
+        ipiv2: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chetrs_rook_64_")]
     pub fn chetrs_rook_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhetrs_rook_64_")]
     pub fn zhetrs_rook_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chfrk_64_")]
     pub fn chfrk_(
         transr: *const c_char,
         uplo: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         alpha: *const f32,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         beta: *const f32,
         C: *mut __BindgenComplex<f32>,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhfrk_64_")]
     pub fn zhfrk_(
         transr: *const c_char,
         uplo: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         alpha: *const f64,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         beta: *const f64,
         C: *mut __BindgenComplex<f64>,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chgeqz_64_")]
     pub fn chgeqz_(
         job: *const c_char,
         compq: *const c_char,
         compz: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         H: *mut __BindgenComplex<f32>,
-        ldh: *const c_int,
+        ldh: *const lapack_int,
         T: *mut __BindgenComplex<f32>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         alpha: *mut __BindgenComplex<f32>,
         beta: *mut __BindgenComplex<f32>,
         Q: *mut __BindgenComplex<f32>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dhgeqz_64_")]
     pub fn dhgeqz_(
         job: *const c_char,
         compq: *const c_char,
         compz: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         H: *mut f64,
-        ldh: *const c_int,
+        ldh: *const lapack_int,
         T: *mut f64,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         alphar: *mut f64,
         alphai: *mut f64,
         beta: *mut f64,
         Q: *mut f64,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "shgeqz_64_")]
     pub fn shgeqz_(
         job: *const c_char,
         compq: *const c_char,
         compz: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         H: *mut f32,
-        ldh: *const c_int,
+        ldh: *const lapack_int,
         T: *mut f32,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         alphar: *mut f32,
         alphai: *mut f32,
         beta: *mut f32,
         Q: *mut f32,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhgeqz_64_")]
     pub fn zhgeqz_(
         job: *const c_char,
         compq: *const c_char,
         compz: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         H: *mut __BindgenComplex<f64>,
-        ldh: *const c_int,
+        ldh: *const lapack_int,
         T: *mut __BindgenComplex<f64>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         alpha: *mut __BindgenComplex<f64>,
         beta: *mut __BindgenComplex<f64>,
         Q: *mut __BindgenComplex<f64>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chpcon_64_")]
     pub fn chpcon_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f32>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         anorm: *const f32,
         rcond: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhpcon_64_")]
     pub fn zhpcon_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f64>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         anorm: *const f64,
         rcond: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chpev_64_")]
     pub fn chpev_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f32>,
         W: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhpev_64_")]
     pub fn zhpev_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f64>,
         W: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chpevd_64_")]
     pub fn chpevd_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f32>,
         W: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhpevd_64_")]
     pub fn zhpevd_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f64>,
         W: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chpevx_64_")]
     pub fn chpevx_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f32>,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhpevx_64_")]
     pub fn zhpevx_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f64>,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chpgst_64_")]
     pub fn chpgst_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f32>,
         BP: *const __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhpgst_64_")]
     pub fn zhpgst_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f64>,
         BP: *const __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chpgv_64_")]
     pub fn chpgv_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f32>,
         BP: *mut __BindgenComplex<f32>,
         W: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhpgv_64_")]
     pub fn zhpgv_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f64>,
         BP: *mut __BindgenComplex<f64>,
         W: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chpgvd_64_")]
     pub fn chpgvd_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f32>,
         BP: *mut __BindgenComplex<f32>,
         W: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhpgvd_64_")]
     pub fn zhpgvd_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f64>,
         BP: *mut __BindgenComplex<f64>,
         W: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chpgvx_64_")]
     pub fn chpgvx_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f32>,
         BP: *mut __BindgenComplex<f32>,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhpgvx_64_")]
     pub fn zhpgvx_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f64>,
         BP: *mut __BindgenComplex<f64>,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chprfs_64_")]
     pub fn chprfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const __BindgenComplex<f32>,
         AFP: *const __BindgenComplex<f32>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhprfs_64_")]
     pub fn zhprfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const __BindgenComplex<f64>,
         AFP: *const __BindgenComplex<f64>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chpsv_64_")]
     pub fn chpsv_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *mut __BindgenComplex<f32>,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhpsv_64_")]
     pub fn zhpsv_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *mut __BindgenComplex<f64>,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chpsvx_64_")]
     pub fn chpsvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const __BindgenComplex<f32>,
         AFP: *mut __BindgenComplex<f32>,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhpsvx_64_")]
     pub fn zhpsvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const __BindgenComplex<f64>,
         AFP: *mut __BindgenComplex<f64>,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chptrd_64_")]
     pub fn chptrd_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f32>,
         D: *mut f32,
         E: *mut f32,
         tau: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhptrd_64_")]
     pub fn zhptrd_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f64>,
         D: *mut f64,
         E: *mut f64,
         tau: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chptrf_64_")]
     pub fn chptrf_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f32>,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhptrf_64_")]
     pub fn zhptrf_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f64>,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chptri_64_")]
     pub fn chptri_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f32>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhptri_64_")]
     pub fn zhptri_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f64>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chptrs_64_")]
     pub fn chptrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const __BindgenComplex<f32>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhptrs_64_")]
     pub fn zhptrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const __BindgenComplex<f64>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chsein_64_")]
     pub fn chsein_(
         side: *const c_char,
         eigsrc: *const c_char,
         initv: *const c_char,
-        select: *const c_int,
-        n: *const c_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         H: *const __BindgenComplex<f32>,
-        ldh: *const c_int,
+        ldh: *const lapack_int,
         W: *mut __BindgenComplex<f32>,
         VL: *mut __BindgenComplex<f32>,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut __BindgenComplex<f32>,
-        ldvr: *const c_int,
-        mm: *const c_int,
-        m: *mut c_int,
+        ldvr: *const lapack_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        IFAILL: *mut c_int,
-        IFAILR: *mut c_int,
-        info: *mut c_int,
+        IFAILL: *mut lapack_int,
+        IFAILR: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dhsein_64_")]
     pub fn dhsein_(
         side: *const c_char,
         eigsrc: *const c_char,
         initv: *const c_char,
-        select: *mut c_int,
-        n: *const c_int,
+        select: *mut lapack_int,
+        n: *const lapack_int,
         H: *const f64,
-        ldh: *const c_int,
+        ldh: *const lapack_int,
         WR: *mut f64,
         WI: *const f64,
         VL: *mut f64,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut f64,
-        ldvr: *const c_int,
-        mm: *const c_int,
-        m: *mut c_int,
+        ldvr: *const lapack_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut f64,
-        IFAILL: *mut c_int,
-        IFAILR: *mut c_int,
-        info: *mut c_int,
+        IFAILL: *mut lapack_int,
+        IFAILR: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "shsein_64_")]
     pub fn shsein_(
         side: *const c_char,
         eigsrc: *const c_char,
         initv: *const c_char,
-        select: *mut c_int,
-        n: *const c_int,
+        select: *mut lapack_int,
+        n: *const lapack_int,
         H: *const f32,
-        ldh: *const c_int,
+        ldh: *const lapack_int,
         WR: *mut f32,
         WI: *const f32,
         VL: *mut f32,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut f32,
-        ldvr: *const c_int,
-        mm: *const c_int,
-        m: *mut c_int,
+        ldvr: *const lapack_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut f32,
-        IFAILL: *mut c_int,
-        IFAILR: *mut c_int,
-        info: *mut c_int,
+        IFAILL: *mut lapack_int,
+        IFAILR: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhsein_64_")]
     pub fn zhsein_(
         side: *const c_char,
         eigsrc: *const c_char,
         initv: *const c_char,
-        select: *const c_int,
-        n: *const c_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         H: *const __BindgenComplex<f64>,
-        ldh: *const c_int,
+        ldh: *const lapack_int,
         W: *mut __BindgenComplex<f64>,
         VL: *mut __BindgenComplex<f64>,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut __BindgenComplex<f64>,
-        ldvr: *const c_int,
-        mm: *const c_int,
-        m: *mut c_int,
+        ldvr: *const lapack_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        IFAILL: *mut c_int,
-        IFAILR: *mut c_int,
-        info: *mut c_int,
+        IFAILL: *mut lapack_int,
+        IFAILR: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "chseqr_64_")]
     pub fn chseqr_(
         job: *const c_char,
         compz: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         H: *mut __BindgenComplex<f32>,
-        ldh: *const c_int,
+        ldh: *const lapack_int,
         W: *mut __BindgenComplex<f32>,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dhseqr_64_")]
     pub fn dhseqr_(
         job: *const c_char,
         compz: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         H: *mut f64,
-        ldh: *const c_int,
+        ldh: *const lapack_int,
         WR: *mut f64,
         WI: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "shseqr_64_")]
     pub fn shseqr_(
         job: *const c_char,
         compz: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         H: *mut f32,
-        ldh: *const c_int,
+        ldh: *const lapack_int,
         WR: *mut f32,
         WI: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zhseqr_64_")]
     pub fn zhseqr_(
         job: *const c_char,
         compz: *const c_char,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         H: *mut __BindgenComplex<f64>,
-        ldh: *const c_int,
+        ldh: *const lapack_int,
         W: *mut __BindgenComplex<f64>,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
-    pub fn clacgv_(n: *const c_int, X: *mut __BindgenComplex<f32>, incx: *const c_int);
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clacgv_64_")]
+    pub fn clacgv_(n: *const lapack_int, X: *mut __BindgenComplex<f32>, incx: *const lapack_int);
 }
 extern "C" {
-    pub fn zlacgv_(n: *const c_int, X: *mut __BindgenComplex<f64>, incx: *const c_int);
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlacgv_64_")]
+    pub fn zlacgv_(n: *const lapack_int, X: *mut __BindgenComplex<f64>, incx: *const lapack_int);
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clacn2_64_")]
     pub fn clacn2_(
-        n: *const c_int,
+        n: *const lapack_int,
         V: *mut __BindgenComplex<f32>,
         X: *mut __BindgenComplex<f32>,
         est: *mut f32,
-        kase: *mut c_int,
-        ISAVE: *mut c_int,
+        kase: *mut lapack_int,
+        ISAVE: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlacn2_64_")]
     pub fn dlacn2_(
-        n: *const c_int,
+        n: *const lapack_int,
         V: *mut f64,
         X: *mut f64,
-        ISGN: *mut c_int,
+        ISGN: *mut lapack_int,
         est: *mut f64,
-        kase: *mut c_int,
-        ISAVE: *mut c_int,
+        kase: *mut lapack_int,
+        ISAVE: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slacn2_64_")]
     pub fn slacn2_(
-        n: *const c_int,
+        n: *const lapack_int,
         V: *mut f32,
         X: *mut f32,
-        ISGN: *mut c_int,
+        ISGN: *mut lapack_int,
         est: *mut f32,
-        kase: *mut c_int,
-        ISAVE: *mut c_int,
+        kase: *mut lapack_int,
+        ISAVE: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlacn2_64_")]
     pub fn zlacn2_(
-        n: *const c_int,
+        n: *const lapack_int,
         V: *mut __BindgenComplex<f64>,
         X: *mut __BindgenComplex<f64>,
         est: *mut f64,
-        kase: *mut c_int,
-        ISAVE: *mut c_int,
+        kase: *mut lapack_int,
+        ISAVE: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clacp2_64_")]
     pub fn clacp2_(
         uplo: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlacp2_64_")]
     pub fn zlacp2_(
         uplo: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clacpy_64_")]
     pub fn clacpy_(
         uplo: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlacpy_64_")]
     pub fn dlacpy_(
         uplo: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slacpy_64_")]
     pub fn slacpy_(
         uplo: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlacpy_64_")]
     pub fn zlacpy_(
         uplo: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clacrm_64_")]
     pub fn clacrm_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         C: *mut __BindgenComplex<f32>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         rwork: *mut f32,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlacrm_64_")]
     pub fn zlacrm_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         C: *mut __BindgenComplex<f64>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         rwork: *mut f64,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlag2c_64_")]
     pub fn zlag2c_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         SA: *mut __BindgenComplex<f32>,
-        ldsa: *const c_int,
-        info: *mut c_int,
+        ldsa: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slag2d_64_")]
     pub fn slag2d_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         SA: *const f32,
-        ldsa: *const c_int,
+        ldsa: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlag2s_64_")]
     pub fn dlag2s_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         SA: *mut f32,
-        ldsa: *const c_int,
-        info: *mut c_int,
+        ldsa: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clag2z_64_")]
     pub fn clag2z_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         SA: *const __BindgenComplex<f32>,
-        ldsa: *const c_int,
+        ldsa: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clagge_64_")]
     pub fn clagge_(
-        m: *const c_int,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         D: *const f32,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        iseed: *mut c_int,
+        lda: *const lapack_int,
+        iseed: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlagge_64_")]
     pub fn dlagge_(
-        m: *const c_int,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         D: *const f64,
         A: *mut f64,
-        lda: *const c_int,
-        iseed: *mut c_int,
+        lda: *const lapack_int,
+        iseed: *mut lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slagge_64_")]
     pub fn slagge_(
-        m: *const c_int,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         D: *const f32,
         A: *mut f32,
-        lda: *const c_int,
-        iseed: *mut c_int,
+        lda: *const lapack_int,
+        iseed: *mut lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlagge_64_")]
     pub fn zlagge_(
-        m: *const c_int,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         D: *const f64,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        iseed: *mut c_int,
+        lda: *const lapack_int,
+        iseed: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "claghe_64_")]
     pub fn claghe_(
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         D: *const f32,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        iseed: *mut c_int,
+        lda: *const lapack_int,
+        iseed: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlaghe_64_")]
     pub fn zlaghe_(
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         D: *const f64,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        iseed: *mut c_int,
+        lda: *const lapack_int,
+        iseed: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clagsy_64_")]
     pub fn clagsy_(
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         D: *const f32,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        iseed: *mut c_int,
+        lda: *const lapack_int,
+        iseed: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlagsy_64_")]
     pub fn dlagsy_(
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         D: *const f64,
         A: *mut f64,
-        lda: *const c_int,
-        iseed: *mut c_int,
+        lda: *const lapack_int,
+        iseed: *mut lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slagsy_64_")]
     pub fn slagsy_(
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         D: *const f32,
         A: *mut f32,
-        lda: *const c_int,
-        iseed: *mut c_int,
+        lda: *const lapack_int,
+        iseed: *mut lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlagsy_64_")]
     pub fn zlagsy_(
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         D: *const f64,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        iseed: *mut c_int,
+        lda: *const lapack_int,
+        iseed: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlamch_64_")]
     pub fn dlamch_(cmach: *const c_char) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slamch_64_")]
     pub fn slamch_(cmach: *const c_char) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clangb_64_")]
     pub fn clangb_(
         norm: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *const __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         work: *mut f32,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlangb_64_")]
     pub fn dlangb_(
         norm: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *const f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         work: *mut f64,
     ) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slangb_64_")]
     pub fn slangb_(
         norm: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *const f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         work: *mut f32,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlangb_64_")]
     pub fn zlangb_(
         norm: *const c_char,
-        n: *const c_int,
-        kl: *const c_int,
-        ku: *const c_int,
+        n: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         AB: *const __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         work: *mut f64,
     ) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clange_64_")]
     pub fn clange_(
         norm: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         work: *mut f32,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlange_64_")]
     pub fn dlange_(
         norm: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         work: *mut f64,
     ) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slange_64_")]
     pub fn slange_(
         norm: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         work: *mut f32,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlange_64_")]
     pub fn zlange_(
         norm: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         work: *mut f64,
     ) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clangt_64_")]
     pub fn clangt_(
         norm: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         DL: *const __BindgenComplex<f32>,
         D: *const __BindgenComplex<f32>,
         DU: *const __BindgenComplex<f32>,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlangt_64_")]
     pub fn dlangt_(
         norm: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         DL: *const f64,
         D: *const f64,
         DU: *const f64,
     ) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slangt_64_")]
     pub fn slangt_(
         norm: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         DL: *const f32,
         D: *const f32,
         DU: *const f32,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlangt_64_")]
     pub fn zlangt_(
         norm: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         DL: *const __BindgenComplex<f64>,
         D: *const __BindgenComplex<f64>,
         DU: *const __BindgenComplex<f64>,
     ) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clanhb_64_")]
     pub fn clanhb_(
         norm: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         AB: *const __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         work: *mut f32,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlanhb_64_")]
     pub fn zlanhb_(
         norm: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         AB: *const __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         work: *mut f64,
     ) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clanhe_64_")]
     pub fn clanhe_(
         norm: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         work: *mut f32,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlanhe_64_")]
     pub fn zlanhe_(
         norm: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         work: *mut f64,
     ) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clanhp_64_")]
     pub fn clanhp_(
         norm: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f32>,
         work: *mut f32,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlanhp_64_")]
     pub fn zlanhp_(
         norm: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f64>,
         work: *mut f64,
     ) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clanhs_64_")]
     pub fn clanhs_(
         norm: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         work: *mut f32,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlanhs_64_")]
     pub fn dlanhs_(
         norm: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         work: *mut f64,
     ) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slanhs_64_")]
     pub fn slanhs_(
         norm: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         work: *mut f32,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlanhs_64_")]
     pub fn zlanhs_(
         norm: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         work: *mut f64,
     ) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clanht_64_")]
     pub fn clanht_(
         norm: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *const f32,
         E: *const __BindgenComplex<f32>,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlanht_64_")]
     pub fn zlanht_(
         norm: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *const f64,
         E: *const __BindgenComplex<f64>,
     ) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clansb_64_")]
     pub fn clansb_(
         norm: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         AB: *const __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         work: *mut f32,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlansb_64_")]
     pub fn dlansb_(
         norm: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         AB: *const f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         work: *mut f64,
     ) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slansb_64_")]
     pub fn slansb_(
         norm: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         AB: *const f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         work: *mut f32,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlansb_64_")]
     pub fn zlansb_(
         norm: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         AB: *const __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         work: *mut f64,
     ) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clansp_64_")]
     pub fn clansp_(
         norm: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f32>,
         work: *mut f32,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlansp_64_")]
     pub fn dlansp_(
         norm: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const f64,
         work: *mut f64,
     ) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slansp_64_")]
     pub fn slansp_(
         norm: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const f32,
         work: *mut f32,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlansp_64_")]
     pub fn zlansp_(
         norm: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f64>,
         work: *mut f64,
     ) -> f64;
 }
 extern "C" {
-    pub fn dlanst_(norm: *const c_char, n: *const c_int, D: *const f64, E: *const f64) -> f64;
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlanst_64_")]
+    pub fn dlanst_(norm: *const c_char, n: *const lapack_int, D: *const f64, E: *const f64) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slanst_64_")]
     pub fn slanst_(
         norm: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *const f32,
         E: *const f32,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clansy_64_")]
     pub fn clansy_(
         norm: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         work: *mut f32,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlansy_64_")]
     pub fn dlansy_(
         norm: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         work: *mut f64,
     ) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slansy_64_")]
     pub fn slansy_(
         norm: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         work: *mut f32,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlansy_64_")]
     pub fn zlansy_(
         norm: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         work: *mut f64,
     ) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clantb_64_")]
     pub fn clantb_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         AB: *const __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         work: *mut f32,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlantb_64_")]
     pub fn dlantb_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         AB: *const f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         work: *mut f64,
     ) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slantb_64_")]
     pub fn slantb_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         AB: *const f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         work: *mut f32,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlantb_64_")]
     pub fn zlantb_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         AB: *const __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         work: *mut f64,
     ) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clantp_64_")]
     pub fn clantp_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f32>,
         work: *mut f32,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlantp_64_")]
     pub fn dlantp_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const f64,
         work: *mut f64,
     ) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slantp_64_")]
     pub fn slantp_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const f32,
         work: *mut f32,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlantp_64_")]
     pub fn zlantp_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f64>,
         work: *mut f64,
     ) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clantr_64_")]
     pub fn clantr_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         work: *mut f32,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlantr_64_")]
     pub fn dlantr_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         work: *mut f64,
     ) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slantr_64_")]
     pub fn slantr_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         work: *mut f32,
     ) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlantr_64_")]
     pub fn zlantr_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         work: *mut f64,
     ) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clapmr_64_")]
     pub fn clapmr_(
-        forwrd: *const c_int,
-        m: *const c_int,
-        n: *const c_int,
+        forwrd: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
-        K: *mut c_int,
+        ldx: *const lapack_int,
+        K: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlapmr_64_")]
     pub fn dlapmr_(
-        forwrd: *const c_int,
-        m: *const c_int,
-        n: *const c_int,
+        forwrd: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
-        K: *mut c_int,
+        ldx: *const lapack_int,
+        K: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slapmr_64_")]
     pub fn slapmr_(
-        forwrd: *const c_int,
-        m: *const c_int,
-        n: *const c_int,
+        forwrd: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
-        K: *mut c_int,
+        ldx: *const lapack_int,
+        K: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlapmr_64_")]
     pub fn zlapmr_(
-        forwrd: *const c_int,
-        m: *const c_int,
-        n: *const c_int,
+        forwrd: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
-        K: *mut c_int,
+        ldx: *const lapack_int,
+        K: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clapmt_64_")]
     pub fn clapmt_(
-        forwrd: *const c_int,
-        m: *const c_int,
-        n: *const c_int,
+        forwrd: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
-        K: *mut c_int,
+        ldx: *const lapack_int,
+        K: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlapmt_64_")]
     pub fn dlapmt_(
-        forwrd: *const c_int,
-        m: *const c_int,
-        n: *const c_int,
+        forwrd: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
-        K: *mut c_int,
+        ldx: *const lapack_int,
+        K: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slapmt_64_")]
     pub fn slapmt_(
-        forwrd: *const c_int,
-        m: *const c_int,
-        n: *const c_int,
+        forwrd: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
-        K: *mut c_int,
+        ldx: *const lapack_int,
+        K: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlapmt_64_")]
     pub fn zlapmt_(
-        forwrd: *const c_int,
-        m: *const c_int,
-        n: *const c_int,
+        forwrd: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
-        K: *mut c_int,
+        ldx: *const lapack_int,
+        K: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlapy2_64_")]
     pub fn dlapy2_(x: *const f64, y: *const f64) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slapy2_64_")]
     pub fn slapy2_(x: *const f32, y: *const f32) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlapy3_64_")]
     pub fn dlapy3_(x: *const f64, y: *const f64, z: *const f64) -> f64;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slapy3_64_")]
     pub fn slapy3_(x: *const f32, y: *const f32, z: *const f32) -> lapack_float_return;
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clarcm_64_")]
     pub fn clarcm_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         C: *mut __BindgenComplex<f32>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         rwork: *mut f32,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlarcm_64_")]
     pub fn zlarcm_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         C: *mut __BindgenComplex<f64>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         rwork: *mut f64,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clarf_64_")]
     pub fn clarf_(
         side: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         V: *const __BindgenComplex<f32>,
-        incv: *const c_int,
+        incv: *const lapack_int,
         tau: *const __BindgenComplex<f32>,
         C: *mut __BindgenComplex<f32>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlarf_64_")]
     pub fn dlarf_(
         side: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         V: *const f64,
-        incv: *const c_int,
+        incv: *const lapack_int,
         tau: *const f64,
         C: *mut f64,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f64,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slarf_64_")]
     pub fn slarf_(
         side: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         V: *const f32,
-        incv: *const c_int,
+        incv: *const lapack_int,
         tau: *const f32,
         C: *mut f32,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f32,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlarf_64_")]
     pub fn zlarf_(
         side: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         V: *const __BindgenComplex<f64>,
-        incv: *const c_int,
+        incv: *const lapack_int,
         tau: *const __BindgenComplex<f64>,
         C: *mut __BindgenComplex<f64>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clarfb_64_")]
     pub fn clarfb_(
         side: *const c_char,
         trans: *const c_char,
         direct: *const c_char,
         storev: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         V: *const __BindgenComplex<f32>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         T: *const __BindgenComplex<f32>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         C: *mut __BindgenComplex<f32>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        ldwork: *const c_int,
+        ldwork: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlarfb_64_")]
     pub fn dlarfb_(
         side: *const c_char,
         trans: *const c_char,
         direct: *const c_char,
         storev: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         V: *const f64,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         T: *const f64,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         C: *mut f64,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f64,
-        ldwork: *const c_int,
+        ldwork: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slarfb_64_")]
     pub fn slarfb_(
         side: *const c_char,
         trans: *const c_char,
         direct: *const c_char,
         storev: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         V: *const f32,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         T: *const f32,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         C: *mut f32,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f32,
-        ldwork: *const c_int,
+        ldwork: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlarfb_64_")]
     pub fn zlarfb_(
         side: *const c_char,
         trans: *const c_char,
         direct: *const c_char,
         storev: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         V: *const __BindgenComplex<f64>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         T: *const __BindgenComplex<f64>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         C: *mut __BindgenComplex<f64>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        ldwork: *const c_int,
+        ldwork: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clarfg_64_")]
     pub fn clarfg_(
-        n: *const c_int,
+        n: *const lapack_int,
         alpha: *mut __BindgenComplex<f32>,
         X: *mut __BindgenComplex<f32>,
-        incx: *const c_int,
+        incx: *const lapack_int,
         tau: *mut __BindgenComplex<f32>,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlarfg_64_")]
     pub fn dlarfg_(
-        n: *const c_int,
+        n: *const lapack_int,
         alpha: *mut f64,
         X: *mut f64,
-        incx: *const c_int,
+        incx: *const lapack_int,
         tau: *mut f64,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slarfg_64_")]
     pub fn slarfg_(
-        n: *const c_int,
+        n: *const lapack_int,
         alpha: *mut f32,
         X: *mut f32,
-        incx: *const c_int,
+        incx: *const lapack_int,
         tau: *mut f32,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlarfg_64_")]
     pub fn zlarfg_(
-        n: *const c_int,
+        n: *const lapack_int,
         alpha: *mut __BindgenComplex<f64>,
         X: *mut __BindgenComplex<f64>,
-        incx: *const c_int,
+        incx: *const lapack_int,
         tau: *mut __BindgenComplex<f64>,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clarft_64_")]
     pub fn clarft_(
         direct: *const c_char,
         storev: *const c_char,
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         V: *const __BindgenComplex<f32>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         tau: *const __BindgenComplex<f32>,
         T: *mut __BindgenComplex<f32>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlarft_64_")]
     pub fn dlarft_(
         direct: *const c_char,
         storev: *const c_char,
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         V: *const f64,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         tau: *const f64,
         T: *mut f64,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slarft_64_")]
     pub fn slarft_(
         direct: *const c_char,
         storev: *const c_char,
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         V: *const f32,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         tau: *const f32,
         T: *mut f32,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlarft_64_")]
     pub fn zlarft_(
         direct: *const c_char,
         storev: *const c_char,
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         V: *const __BindgenComplex<f64>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         tau: *const __BindgenComplex<f64>,
         T: *mut __BindgenComplex<f64>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clarfx_64_")]
     pub fn clarfx_(
         side: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         V: *const __BindgenComplex<f32>,
         tau: *const __BindgenComplex<f32>,
         C: *mut __BindgenComplex<f32>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlarfx_64_")]
     pub fn dlarfx_(
         side: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         V: *const f64,
         tau: *const f64,
         C: *mut f64,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f64,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slarfx_64_")]
     pub fn slarfx_(
         side: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         V: *const f32,
         tau: *const f32,
         C: *mut f32,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f32,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlarfx_64_")]
     pub fn zlarfx_(
         side: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         V: *const __BindgenComplex<f64>,
         tau: *const __BindgenComplex<f64>,
         C: *mut __BindgenComplex<f64>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clarnv_64_")]
     pub fn clarnv_(
-        idist: *const c_int,
-        iseed: *mut c_int,
-        n: *const c_int,
+        idist: *const lapack_int,
+        iseed: *mut lapack_int,
+        n: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
     );
 }
 extern "C" {
-    pub fn dlarnv_(idist: *const c_int, iseed: *mut c_int, n: *const c_int, X: *mut f64);
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlarnv_64_")]
+    pub fn dlarnv_(
+        idist: *const lapack_int,
+        iseed: *mut lapack_int,
+        n: *const lapack_int,
+        X: *mut f64,
+    );
 }
 extern "C" {
-    pub fn slarnv_(idist: *const c_int, iseed: *mut c_int, n: *const c_int, X: *mut f32);
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slarnv_64_")]
+    pub fn slarnv_(
+        idist: *const lapack_int,
+        iseed: *mut lapack_int,
+        n: *const lapack_int,
+        X: *mut f32,
+    );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlarnv_64_")]
     pub fn zlarnv_(
-        idist: *const c_int,
-        iseed: *mut c_int,
-        n: *const c_int,
+        idist: *const lapack_int,
+        iseed: *mut lapack_int,
+        n: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlartgp_64_")]
     pub fn dlartgp_(f: *const f64, g: *const f64, cs: *mut f64, sn: *mut f64, r: *mut f64);
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slartgp_64_")]
     pub fn slartgp_(f: *const f32, g: *const f32, cs: *mut f32, sn: *mut f32, r: *mut f32);
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlartgs_64_")]
     pub fn dlartgs_(x: *const f64, y: *const f64, sigma: *const f64, cs: *mut f64, sn: *mut f64);
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slartgs_64_")]
     pub fn slartgs_(x: *const f32, y: *const f32, sigma: *const f32, cs: *mut f32, sn: *mut f32);
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clascl_64_")]
     pub fn clascl_(
         type_: *const c_char,
-        kl: *const c_int,
-        ku: *const c_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         cfrom: *const f32,
         cto: *const f32,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlascl_64_")]
     pub fn dlascl_(
         type_: *const c_char,
-        kl: *const c_int,
-        ku: *const c_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         cfrom: *const f64,
         cto: *const f64,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slascl_64_")]
     pub fn slascl_(
         type_: *const c_char,
-        kl: *const c_int,
-        ku: *const c_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         cfrom: *const f32,
         cto: *const f32,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlascl_64_")]
     pub fn zlascl_(
         type_: *const c_char,
-        kl: *const c_int,
-        ku: *const c_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         cfrom: *const f64,
         cto: *const f64,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "claset_64_")]
     pub fn claset_(
         uplo: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         alpha: *const __BindgenComplex<f32>,
         beta: *const __BindgenComplex<f32>,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlaset_64_")]
     pub fn dlaset_(
         uplo: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         alpha: *const f64,
         beta: *const f64,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slaset_64_")]
     pub fn slaset_(
         uplo: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         alpha: *const f32,
         beta: *const f32,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlaset_64_")]
     pub fn zlaset_(
         uplo: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         alpha: *const __BindgenComplex<f64>,
         beta: *const __BindgenComplex<f64>,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
     );
 }
 extern "C" {
-    pub fn dlasrt_(id: *const c_char, n: *const c_int, D: *mut f64, info: *mut c_int);
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlasrt_64_")]
+    pub fn dlasrt_(id: *const c_char, n: *const lapack_int, D: *mut f64, info: *mut lapack_int);
 }
 extern "C" {
-    pub fn slasrt_(id: *const c_char, n: *const c_int, D: *mut f32, info: *mut c_int);
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slasrt_64_")]
+    pub fn slasrt_(id: *const c_char, n: *const lapack_int, D: *mut f32, info: *mut lapack_int);
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "classq_64_")]
     pub fn classq_(
-        n: *const c_int,
+        n: *const lapack_int,
         X: *const __BindgenComplex<f32>,
-        incx: *const c_int,
+        incx: *const lapack_int,
         scale: *mut f32,
         sumsq: *mut f32,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlassq_64_")]
     pub fn dlassq_(
-        n: *const c_int,
+        n: *const lapack_int,
         X: *const f64,
-        incx: *const c_int,
+        incx: *const lapack_int,
         scale: *mut f64,
         sumsq: *mut f64,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slassq_64_")]
     pub fn slassq_(
-        n: *const c_int,
+        n: *const lapack_int,
         X: *const f32,
-        incx: *const c_int,
+        incx: *const lapack_int,
         scale: *mut f32,
         sumsq: *mut f32,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlassq_64_")]
     pub fn zlassq_(
-        n: *const c_int,
+        n: *const lapack_int,
         X: *const __BindgenComplex<f64>,
-        incx: *const c_int,
+        incx: *const lapack_int,
         scale: *mut f64,
         sumsq: *mut f64,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "claswp_64_")]
     pub fn claswp_(
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        k1: *const c_int,
-        k2: *const c_int,
-        ipiv: *const c_int,
-        incx: *const c_int,
+        lda: *const lapack_int,
+        k1: *const lapack_int,
+        k2: *const lapack_int,
+        ipiv: *const lapack_int,
+        incx: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlaswp_64_")]
     pub fn dlaswp_(
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        k1: *const c_int,
-        k2: *const c_int,
-        ipiv: *const c_int,
-        incx: *const c_int,
+        lda: *const lapack_int,
+        k1: *const lapack_int,
+        k2: *const lapack_int,
+        ipiv: *const lapack_int,
+        incx: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slaswp_64_")]
     pub fn slaswp_(
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        k1: *const c_int,
-        k2: *const c_int,
-        ipiv: *const c_int,
-        incx: *const c_int,
+        lda: *const lapack_int,
+        k1: *const lapack_int,
+        k2: *const lapack_int,
+        ipiv: *const lapack_int,
+        incx: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlaswp_64_")]
     pub fn zlaswp_(
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        k1: *const c_int,
-        k2: *const c_int,
-        ipiv: *const c_int,
-        incx: *const c_int,
+        lda: *const lapack_int,
+        k1: *const lapack_int,
+        k2: *const lapack_int,
+        ipiv: *const lapack_int,
+        incx: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clatms_64_")]
     pub fn clatms_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         dist: *const c_char,
-        iseed: *mut c_int,
+        iseed: *mut lapack_int,
         sym: *const c_char,
         D: *mut f32,
-        mode: *const c_int,
+        mode: *const lapack_int,
         cond: *const f32,
         dmax: *const f32,
-        kl: *const c_int,
-        ku: *const c_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         pack: *const c_char,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlatms_64_")]
     pub fn dlatms_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         dist: *const c_char,
-        iseed: *mut c_int,
+        iseed: *mut lapack_int,
         sym: *const c_char,
         D: *mut f64,
-        mode: *const c_int,
+        mode: *const lapack_int,
         cond: *const f64,
         dmax: *const f64,
-        kl: *const c_int,
-        ku: *const c_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         pack: *const c_char,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slatms_64_")]
     pub fn slatms_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         dist: *const c_char,
-        iseed: *mut c_int,
+        iseed: *mut lapack_int,
         sym: *const c_char,
         D: *mut f32,
-        mode: *const c_int,
+        mode: *const lapack_int,
         cond: *const f32,
         dmax: *const f32,
-        kl: *const c_int,
-        ku: *const c_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         pack: *const c_char,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlatms_64_")]
     pub fn zlatms_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         dist: *const c_char,
-        iseed: *mut c_int,
+        iseed: *mut lapack_int,
         sym: *const c_char,
         D: *mut f64,
-        mode: *const c_int,
+        mode: *const lapack_int,
         cond: *const f64,
         dmax: *const f64,
-        kl: *const c_int,
-        ku: *const c_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
         pack: *const c_char,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clauum_64_")]
     pub fn clauum_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlauum_64_")]
     pub fn dlauum_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slauum_64_")]
     pub fn slauum_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlauum_64_")]
     pub fn zlauum_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
-    pub fn ilaver_(vers_major: *mut c_int, vers_minor: *mut c_int, vers_patch: *mut c_int);
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ilaver_64_")]
+    pub fn ilaver_(
+        vers_major: *mut lapack_int,
+        vers_minor: *mut lapack_int,
+        vers_patch: *mut lapack_int,
+    );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dopgtr_64_")]
     pub fn dopgtr_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const f64,
         tau: *const f64,
         Q: *mut f64,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sopgtr_64_")]
     pub fn sopgtr_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const f32,
         tau: *const f32,
         Q: *mut f32,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dopmtr_64_")]
     pub fn dopmtr_(
         side: *const c_char,
         uplo: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         AP: *const f64,
         tau: *const f64,
         C: *mut f64,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sopmtr_64_")]
     pub fn sopmtr_(
         side: *const c_char,
         uplo: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         AP: *const f32,
         tau: *const f32,
         C: *mut f32,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dorbdb_64_")]
     pub fn dorbdb_(
         trans: *const c_char,
         signs: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        q: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        q: *const lapack_int,
         X11: *mut f64,
-        ldx11: *const c_int,
+        ldx11: *const lapack_int,
         X12: *mut f64,
-        ldx12: *const c_int,
+        ldx12: *const lapack_int,
         X21: *mut f64,
-        ldx21: *const c_int,
+        ldx21: *const lapack_int,
         X22: *mut f64,
-        ldx22: *const c_int,
+        ldx22: *const lapack_int,
         theta: *mut f64,
         phi: *mut f64,
         TAUP1: *mut f64,
@@ -10908,25 +11606,26 @@ extern "C" {
         TAUQ1: *mut f64,
         TAUQ2: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sorbdb_64_")]
     pub fn sorbdb_(
         trans: *const c_char,
         signs: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        q: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        q: *const lapack_int,
         X11: *mut f32,
-        ldx11: *const c_int,
+        ldx11: *const lapack_int,
         X12: *mut f32,
-        ldx12: *const c_int,
+        ldx12: *const lapack_int,
         X21: *mut f32,
-        ldx21: *const c_int,
+        ldx21: *const lapack_int,
         X22: *mut f32,
-        ldx22: *const c_int,
+        ldx22: *const lapack_int,
         theta: *mut f32,
         phi: *mut f32,
         TAUP1: *mut f32,
@@ -10934,11 +11633,12 @@ extern "C" {
         TAUQ1: *mut f32,
         TAUQ2: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dorcsd_64_")]
     pub fn dorcsd_(
         jobu1: *const c_char,
         jobu2: *const c_char,
@@ -10946,33 +11646,34 @@ extern "C" {
         jobv2t: *const c_char,
         trans: *const c_char,
         signs: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        q: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        q: *const lapack_int,
         X11: *mut f64,
-        ldx11: *const c_int,
+        ldx11: *const lapack_int,
         X12: *mut f64,
-        ldx12: *const c_int,
+        ldx12: *const lapack_int,
         X21: *mut f64,
-        ldx21: *const c_int,
+        ldx21: *const lapack_int,
         X22: *mut f64,
-        ldx22: *const c_int,
+        ldx22: *const lapack_int,
         theta: *mut f64,
         U1: *mut f64,
-        ldu1: *const c_int,
+        ldu1: *const lapack_int,
         U2: *mut f64,
-        ldu2: *const c_int,
+        ldu2: *const lapack_int,
         V1T: *mut f64,
-        ldv1t: *const c_int,
+        ldv1t: *const lapack_int,
         V2T: *mut f64,
-        ldv2t: *const c_int,
+        ldv2t: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sorcsd_64_")]
     pub fn sorcsd_(
         jobu1: *const c_char,
         jobu2: *const c_char,
@@ -10980,9384 +11681,9995 @@ extern "C" {
         jobv2t: *const c_char,
         trans: *const c_char,
         signs: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        q: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        q: *const lapack_int,
         X11: *mut f32,
-        ldx11: *const c_int,
+        ldx11: *const lapack_int,
         X12: *mut f32,
-        ldx12: *const c_int,
+        ldx12: *const lapack_int,
         X21: *mut f32,
-        ldx21: *const c_int,
+        ldx21: *const lapack_int,
         X22: *mut f32,
-        ldx22: *const c_int,
+        ldx22: *const lapack_int,
         theta: *mut f32,
         U1: *mut f32,
-        ldu1: *const c_int,
+        ldu1: *const lapack_int,
         U2: *mut f32,
-        ldu2: *const c_int,
+        ldu2: *const lapack_int,
         V1T: *mut f32,
-        ldv1t: *const c_int,
+        ldv1t: *const lapack_int,
         V2T: *mut f32,
-        ldv2t: *const c_int,
+        ldv2t: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dorcsd2by1_64_")]
     pub fn dorcsd2by1_(
         jobu1: *const c_char,
         jobu2: *const c_char,
         jobv1t: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        q: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        q: *const lapack_int,
         X11: *mut f64,
-        ldx11: *const c_int,
+        ldx11: *const lapack_int,
         X21: *mut f64,
-        ldx21: *const c_int,
+        ldx21: *const lapack_int,
         theta: *mut f64,
         U1: *mut f64,
-        ldu1: *const c_int,
+        ldu1: *const lapack_int,
         U2: *mut f64,
-        ldu2: *const c_int,
+        ldu2: *const lapack_int,
         V1T: *mut f64,
-        ldv1t: *const c_int,
+        ldv1t: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sorcsd2by1_64_")]
     pub fn sorcsd2by1_(
         jobu1: *const c_char,
         jobu2: *const c_char,
         jobv1t: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        q: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        q: *const lapack_int,
         X11: *mut f32,
-        ldx11: *const c_int,
+        ldx11: *const lapack_int,
         X21: *mut f32,
-        ldx21: *const c_int,
+        ldx21: *const lapack_int,
         theta: *mut f32,
         U1: *mut f32,
-        ldu1: *const c_int,
+        ldu1: *const lapack_int,
         U2: *mut f32,
-        ldu2: *const c_int,
+        ldu2: *const lapack_int,
         V1T: *mut f32,
-        ldv1t: *const c_int,
+        ldv1t: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dorgbr_64_")]
     pub fn dorgbr_(
         vect: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sorgbr_64_")]
     pub fn sorgbr_(
         vect: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dorghr_64_")]
     pub fn dorghr_(
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sorghr_64_")]
     pub fn sorghr_(
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dorglq_64_")]
     pub fn dorglq_(
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sorglq_64_")]
     pub fn sorglq_(
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dorgql_64_")]
     pub fn dorgql_(
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sorgql_64_")]
     pub fn sorgql_(
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dorgqr_64_")]
     pub fn dorgqr_(
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sorgqr_64_")]
     pub fn sorgqr_(
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dorgrq_64_")]
     pub fn dorgrq_(
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sorgrq_64_")]
     pub fn sorgrq_(
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dorgtr_64_")]
     pub fn dorgtr_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sorgtr_64_")]
     pub fn sorgtr_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dorgtsqr_row_64_")]
     pub fn dorgtsqr_row_(
-        m: *const c_int,
-        n: *const c_int,
-        mb: *const c_int,
-        nb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        mb: *const lapack_int,
+        nb: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *const f64,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sorgtsqr_row_64_")]
     pub fn sorgtsqr_row_(
-        m: *const c_int,
-        n: *const c_int,
-        mb: *const c_int,
-        nb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        mb: *const lapack_int,
+        nb: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *const f32,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dormbr_64_")]
     pub fn dormbr_(
         vect: *const c_char,
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f64,
         C: *mut f64,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sormbr_64_")]
     pub fn sormbr_(
         vect: *const c_char,
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f32,
         C: *mut f32,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dormhr_64_")]
     pub fn dormhr_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f64,
         C: *mut f64,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sormhr_64_")]
     pub fn sormhr_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f32,
         C: *mut f32,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dormlq_64_")]
     pub fn dormlq_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f64,
         C: *mut f64,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sormlq_64_")]
     pub fn sormlq_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f32,
         C: *mut f32,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dormql_64_")]
     pub fn dormql_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f64,
         C: *mut f64,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sormql_64_")]
     pub fn sormql_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f32,
         C: *mut f32,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dormqr_64_")]
     pub fn dormqr_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f64,
         C: *mut f64,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sormqr_64_")]
     pub fn sormqr_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f32,
         C: *mut f32,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dormrq_64_")]
     pub fn dormrq_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f64,
         C: *mut f64,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sormrq_64_")]
     pub fn sormrq_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f32,
         C: *mut f32,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dormrz_64_")]
     pub fn dormrz_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        l: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        l: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f64,
         C: *mut f64,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sormrz_64_")]
     pub fn sormrz_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        l: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        l: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f32,
         C: *mut f32,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dormtr_64_")]
     pub fn dormtr_(
         side: *const c_char,
         uplo: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f64,
         C: *mut f64,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sormtr_64_")]
     pub fn sormtr_(
         side: *const c_char,
         uplo: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const f32,
         C: *mut f32,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpbcon_64_")]
     pub fn cpbcon_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *const __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         anorm: *const f32,
         rcond: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpbcon_64_")]
     pub fn dpbcon_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *const f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         anorm: *const f64,
         rcond: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spbcon_64_")]
     pub fn spbcon_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *const f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         anorm: *const f32,
         rcond: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpbcon_64_")]
     pub fn zpbcon_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *const __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         anorm: *const f64,
         rcond: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpbequ_64_")]
     pub fn cpbequ_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *const __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         S: *mut f32,
         scond: *mut f32,
         amax: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpbequ_64_")]
     pub fn dpbequ_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *const f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         S: *mut f64,
         scond: *mut f64,
         amax: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spbequ_64_")]
     pub fn spbequ_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *const f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         S: *mut f32,
         scond: *mut f32,
         amax: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpbequ_64_")]
     pub fn zpbequ_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *const __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         S: *mut f64,
         scond: *mut f64,
         amax: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpbrfs_64_")]
     pub fn cpbrfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *const __BindgenComplex<f32>,
-        ldafb: *const c_int,
+        ldafb: *const lapack_int,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpbrfs_64_")]
     pub fn dpbrfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *const f64,
-        ldafb: *const c_int,
+        ldafb: *const lapack_int,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spbrfs_64_")]
     pub fn spbrfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *const f32,
-        ldafb: *const c_int,
+        ldafb: *const lapack_int,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpbrfs_64_")]
     pub fn zpbrfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *const __BindgenComplex<f64>,
-        ldafb: *const c_int,
+        ldafb: *const lapack_int,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpbstf_64_")]
     pub fn cpbstf_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut __BindgenComplex<f32>,
-        ldab: *const c_int,
-        info: *mut c_int,
+        ldab: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpbstf_64_")]
     pub fn dpbstf_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut f64,
-        ldab: *const c_int,
-        info: *mut c_int,
+        ldab: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spbstf_64_")]
     pub fn spbstf_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut f32,
-        ldab: *const c_int,
-        info: *mut c_int,
+        ldab: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpbstf_64_")]
     pub fn zpbstf_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut __BindgenComplex<f64>,
-        ldab: *const c_int,
-        info: *mut c_int,
+        ldab: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpbsv_64_")]
     pub fn cpbsv_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *mut __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpbsv_64_")]
     pub fn dpbsv_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *mut f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spbsv_64_")]
     pub fn spbsv_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *mut f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpbsv_64_")]
     pub fn zpbsv_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *mut __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpbsvx_64_")]
     pub fn cpbsvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *mut __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *mut __BindgenComplex<f32>,
-        ldafb: *const c_int,
+        ldafb: *const lapack_int,
         equed: *mut c_char,
         S: *mut f32,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpbsvx_64_")]
     pub fn dpbsvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *mut f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *mut f64,
-        ldafb: *const c_int,
+        ldafb: *const lapack_int,
         equed: *mut c_char,
         S: *mut f64,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spbsvx_64_")]
     pub fn spbsvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *mut f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *mut f32,
-        ldafb: *const c_int,
+        ldafb: *const lapack_int,
         equed: *mut c_char,
         S: *mut f32,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpbsvx_64_")]
     pub fn zpbsvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *mut __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         AFB: *mut __BindgenComplex<f64>,
-        ldafb: *const c_int,
+        ldafb: *const lapack_int,
         equed: *mut c_char,
         S: *mut f64,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpbtrf_64_")]
     pub fn cpbtrf_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut __BindgenComplex<f32>,
-        ldab: *const c_int,
-        info: *mut c_int,
+        ldab: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpbtrf_64_")]
     pub fn dpbtrf_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut f64,
-        ldab: *const c_int,
-        info: *mut c_int,
+        ldab: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spbtrf_64_")]
     pub fn spbtrf_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut f32,
-        ldab: *const c_int,
-        info: *mut c_int,
+        ldab: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpbtrf_64_")]
     pub fn zpbtrf_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut __BindgenComplex<f64>,
-        ldab: *const c_int,
-        info: *mut c_int,
+        ldab: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpbtrs_64_")]
     pub fn cpbtrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpbtrs_64_")]
     pub fn dpbtrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spbtrs_64_")]
     pub fn spbtrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpbtrs_64_")]
     pub fn zpbtrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpftrf_64_")]
     pub fn cpftrf_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpftrf_64_")]
     pub fn dpftrf_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spftrf_64_")]
     pub fn spftrf_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpftrf_64_")]
     pub fn zpftrf_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpftri_64_")]
     pub fn cpftri_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpftri_64_")]
     pub fn dpftri_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spftri_64_")]
     pub fn spftri_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpftri_64_")]
     pub fn zpftri_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpftrs_64_")]
     pub fn cpftrs_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpftrs_64_")]
     pub fn dpftrs_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f64,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spftrs_64_")]
     pub fn spftrs_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f32,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpftrs_64_")]
     pub fn zpftrs_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpocon_64_")]
     pub fn cpocon_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         anorm: *const f32,
         rcond: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpocon_64_")]
     pub fn dpocon_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         anorm: *const f64,
         rcond: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spocon_64_")]
     pub fn spocon_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         anorm: *const f32,
         rcond: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpocon_64_")]
     pub fn zpocon_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         anorm: *const f64,
         rcond: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpoequ_64_")]
     pub fn cpoequ_(
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f32,
         scond: *mut f32,
         amax: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpoequ_64_")]
     pub fn dpoequ_(
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f64,
         scond: *mut f64,
         amax: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spoequ_64_")]
     pub fn spoequ_(
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f32,
         scond: *mut f32,
         amax: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpoequ_64_")]
     pub fn zpoequ_(
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f64,
         scond: *mut f64,
         amax: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpoequb_64_")]
     pub fn cpoequb_(
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f32,
         scond: *mut f32,
         amax: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpoequb_64_")]
     pub fn dpoequb_(
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f64,
         scond: *mut f64,
         amax: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spoequb_64_")]
     pub fn spoequb_(
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f32,
         scond: *mut f32,
         amax: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpoequb_64_")]
     pub fn zpoequb_(
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f64,
         scond: *mut f64,
         amax: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cporfs_64_")]
     pub fn cporfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const __BindgenComplex<f32>,
-        ldaf: *const c_int,
+        ldaf: *const lapack_int,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dporfs_64_")]
     pub fn dporfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const f64,
-        ldaf: *const c_int,
+        ldaf: *const lapack_int,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sporfs_64_")]
     pub fn sporfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const f32,
-        ldaf: *const c_int,
+        ldaf: *const lapack_int,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zporfs_64_")]
     pub fn zporfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const __BindgenComplex<f64>,
-        ldaf: *const c_int,
+        ldaf: *const lapack_int,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cporfsx_64_")]
     pub fn cporfsx_(
         uplo: *const c_char,
         equed: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const __BindgenComplex<f32>,
-        ldaf: *const c_int,
+        ldaf: *const lapack_int,
         S: *mut f32,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         berr: *mut f32,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f32,
         err_bnds_comp: *mut f32,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dporfsx_64_")]
     pub fn dporfsx_(
         uplo: *const c_char,
         equed: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const f64,
-        ldaf: *const c_int,
+        ldaf: *const lapack_int,
         S: *mut f64,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         berr: *mut f64,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f64,
         err_bnds_comp: *mut f64,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sporfsx_64_")]
     pub fn sporfsx_(
         uplo: *const c_char,
         equed: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const f32,
-        ldaf: *const c_int,
+        ldaf: *const lapack_int,
         S: *mut f32,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         berr: *mut f32,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f32,
         err_bnds_comp: *mut f32,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zporfsx_64_")]
     pub fn zporfsx_(
         uplo: *const c_char,
         equed: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const __BindgenComplex<f64>,
-        ldaf: *const c_int,
+        ldaf: *const lapack_int,
         S: *mut f64,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         berr: *mut f64,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f64,
         err_bnds_comp: *mut f64,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cposv_64_")]
     pub fn cposv_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dposv_64_")]
     pub fn dposv_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sposv_64_")]
     pub fn sposv_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zposv_64_")]
     pub fn zposv_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsposv_64_")]
     pub fn dsposv_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         work: *mut f64,
         swork: *mut f32,
-        iter: *mut c_int,
-        info: *mut c_int,
+        iter: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zcposv_64_")]
     pub fn zcposv_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
         swork: *mut __BindgenComplex<f32>,
         rwork: *mut f64,
-        iter: *mut c_int,
-        info: *mut c_int,
+        iter: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cposvx_64_")]
     pub fn cposvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut __BindgenComplex<f32>,
-        ldaf: *const c_int,
+        ldaf: *const lapack_int,
         equed: *mut c_char,
         S: *mut f32,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dposvx_64_")]
     pub fn dposvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut f64,
-        ldaf: *const c_int,
+        ldaf: *const lapack_int,
         equed: *mut c_char,
         S: *mut f64,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sposvx_64_")]
     pub fn sposvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut f32,
-        ldaf: *const c_int,
+        ldaf: *const lapack_int,
         equed: *mut c_char,
         S: *mut f32,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zposvx_64_")]
     pub fn zposvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut __BindgenComplex<f64>,
-        ldaf: *const c_int,
+        ldaf: *const lapack_int,
         equed: *mut c_char,
         S: *mut f64,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cposvxx_64_")]
     pub fn cposvxx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut __BindgenComplex<f32>,
-        ldaf: *const c_int,
+        ldaf: *const lapack_int,
         equed: *mut c_char,
         S: *mut f32,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         rpvgrw: *mut f32,
         berr: *mut f32,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f32,
         err_bnds_comp: *mut f32,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dposvxx_64_")]
     pub fn dposvxx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut f64,
-        ldaf: *const c_int,
+        ldaf: *const lapack_int,
         equed: *mut c_char,
         S: *mut f64,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         rpvgrw: *mut f64,
         berr: *mut f64,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f64,
         err_bnds_comp: *mut f64,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sposvxx_64_")]
     pub fn sposvxx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut f32,
-        ldaf: *const c_int,
+        ldaf: *const lapack_int,
         equed: *mut c_char,
         S: *mut f32,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         rpvgrw: *mut f32,
         berr: *mut f32,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f32,
         err_bnds_comp: *mut f32,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zposvxx_64_")]
     pub fn zposvxx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut __BindgenComplex<f64>,
-        ldaf: *const c_int,
+        ldaf: *const lapack_int,
         equed: *mut c_char,
         S: *mut f64,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         rpvgrw: *mut f64,
         berr: *mut f64,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f64,
         err_bnds_comp: *mut f64,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpotf2_64_")]
     pub fn cpotf2_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpotf2_64_")]
     pub fn dpotf2_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spotf2_64_")]
     pub fn spotf2_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpotf2_64_")]
     pub fn zpotf2_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpotrf_64_")]
     pub fn cpotrf_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpotrf_64_")]
     pub fn dpotrf_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spotrf_64_")]
     pub fn spotrf_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpotrf_64_")]
     pub fn zpotrf_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpotrf2_64_")]
     pub fn cpotrf2_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpotrf2_64_")]
     pub fn dpotrf2_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spotrf2_64_")]
     pub fn spotrf2_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpotrf2_64_")]
     pub fn zpotrf2_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpotri_64_")]
     pub fn cpotri_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpotri_64_")]
     pub fn dpotri_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spotri_64_")]
     pub fn spotri_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpotri_64_")]
     pub fn zpotri_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpotrs_64_")]
     pub fn cpotrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpotrs_64_")]
     pub fn dpotrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spotrs_64_")]
     pub fn spotrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpotrs_64_")]
     pub fn zpotrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cppcon_64_")]
     pub fn cppcon_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f32>,
         anorm: *const f32,
         rcond: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dppcon_64_")]
     pub fn dppcon_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const f64,
         anorm: *const f64,
         rcond: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sppcon_64_")]
     pub fn sppcon_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const f32,
         anorm: *const f32,
         rcond: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zppcon_64_")]
     pub fn zppcon_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f64>,
         anorm: *const f64,
         rcond: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cppequ_64_")]
     pub fn cppequ_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f32>,
         S: *mut f32,
         scond: *mut f32,
         amax: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dppequ_64_")]
     pub fn dppequ_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const f64,
         S: *mut f64,
         scond: *mut f64,
         amax: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sppequ_64_")]
     pub fn sppequ_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const f32,
         S: *mut f32,
         scond: *mut f32,
         amax: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zppequ_64_")]
     pub fn zppequ_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f64>,
         S: *mut f64,
         scond: *mut f64,
         amax: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpprfs_64_")]
     pub fn cpprfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const __BindgenComplex<f32>,
         AFP: *const __BindgenComplex<f32>,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpprfs_64_")]
     pub fn dpprfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const f64,
         AFP: *const f64,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spprfs_64_")]
     pub fn spprfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const f32,
         AFP: *const f32,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpprfs_64_")]
     pub fn zpprfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const __BindgenComplex<f64>,
         AFP: *const __BindgenComplex<f64>,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cppsv_64_")]
     pub fn cppsv_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *mut __BindgenComplex<f32>,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dppsv_64_")]
     pub fn dppsv_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *mut f64,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sppsv_64_")]
     pub fn sppsv_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *mut f32,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zppsv_64_")]
     pub fn zppsv_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *mut __BindgenComplex<f64>,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cppsvx_64_")]
     pub fn cppsvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *mut __BindgenComplex<f32>,
         AFP: *mut __BindgenComplex<f32>,
         equed: *mut c_char,
         S: *mut f32,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dppsvx_64_")]
     pub fn dppsvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *mut f64,
         AFP: *mut f64,
         equed: *mut c_char,
         S: *mut f64,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sppsvx_64_")]
     pub fn sppsvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *mut f32,
         AFP: *mut f32,
         equed: *mut c_char,
         S: *mut f32,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zppsvx_64_")]
     pub fn zppsvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *mut __BindgenComplex<f64>,
         AFP: *mut __BindgenComplex<f64>,
         equed: *mut c_char,
         S: *mut f64,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpptrf_64_")]
     pub fn cpptrf_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
-    pub fn dpptrf_(uplo: *const c_char, n: *const c_int, AP: *mut f64, info: *mut c_int);
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpptrf_64_")]
+    pub fn dpptrf_(uplo: *const c_char, n: *const lapack_int, AP: *mut f64, info: *mut lapack_int);
 }
 extern "C" {
-    pub fn spptrf_(uplo: *const c_char, n: *const c_int, AP: *mut f32, info: *mut c_int);
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spptrf_64_")]
+    pub fn spptrf_(uplo: *const c_char, n: *const lapack_int, AP: *mut f32, info: *mut lapack_int);
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpptrf_64_")]
     pub fn zpptrf_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpptri_64_")]
     pub fn cpptri_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
-    pub fn dpptri_(uplo: *const c_char, n: *const c_int, AP: *mut f64, info: *mut c_int);
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpptri_64_")]
+    pub fn dpptri_(uplo: *const c_char, n: *const lapack_int, AP: *mut f64, info: *mut lapack_int);
 }
 extern "C" {
-    pub fn spptri_(uplo: *const c_char, n: *const c_int, AP: *mut f32, info: *mut c_int);
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spptri_64_")]
+    pub fn spptri_(uplo: *const c_char, n: *const lapack_int, AP: *mut f32, info: *mut lapack_int);
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpptri_64_")]
     pub fn zpptri_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpptrs_64_")]
     pub fn cpptrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const __BindgenComplex<f32>,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpptrs_64_")]
     pub fn dpptrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const f64,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spptrs_64_")]
     pub fn spptrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const f32,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpptrs_64_")]
     pub fn zpptrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const __BindgenComplex<f64>,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpstrf_64_")]
     pub fn cpstrf_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        piv: *mut c_int,
-        rank: *mut c_int,
+        lda: *const lapack_int,
+        piv: *mut lapack_int,
+        rank: *mut lapack_int,
         tol: *const f32,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpstrf_64_")]
     pub fn dpstrf_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        piv: *mut c_int,
-        rank: *mut c_int,
+        lda: *const lapack_int,
+        piv: *mut lapack_int,
+        rank: *mut lapack_int,
         tol: *const f64,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spstrf_64_")]
     pub fn spstrf_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        piv: *mut c_int,
-        rank: *mut c_int,
+        lda: *const lapack_int,
+        piv: *mut lapack_int,
+        rank: *mut lapack_int,
         tol: *const f32,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpstrf_64_")]
     pub fn zpstrf_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        piv: *mut c_int,
-        rank: *mut c_int,
+        lda: *const lapack_int,
+        piv: *mut lapack_int,
+        rank: *mut lapack_int,
         tol: *const f64,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cptcon_64_")]
     pub fn cptcon_(
-        n: *const c_int,
+        n: *const lapack_int,
         D: *const f32,
         E: *const __BindgenComplex<f32>,
         anorm: *const f32,
         rcond: *mut f32,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dptcon_64_")]
     pub fn dptcon_(
-        n: *const c_int,
+        n: *const lapack_int,
         D: *const f64,
         E: *const f64,
         anorm: *const f64,
         rcond: *mut f64,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sptcon_64_")]
     pub fn sptcon_(
-        n: *const c_int,
+        n: *const lapack_int,
         D: *const f32,
         E: *const f32,
         anorm: *const f32,
         rcond: *mut f32,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zptcon_64_")]
     pub fn zptcon_(
-        n: *const c_int,
+        n: *const lapack_int,
         D: *const f64,
         E: *const __BindgenComplex<f64>,
         anorm: *const f64,
         rcond: *mut f64,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpteqr_64_")]
     pub fn cpteqr_(
         compz: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpteqr_64_")]
     pub fn dpteqr_(
         compz: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spteqr_64_")]
     pub fn spteqr_(
         compz: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpteqr_64_")]
     pub fn zpteqr_(
         compz: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cptrfs_64_")]
     pub fn cptrfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         D: *const f32,
         E: *const __BindgenComplex<f32>,
         DF: *const f32,
         EF: *const __BindgenComplex<f32>,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dptrfs_64_")]
     pub fn dptrfs_(
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         D: *const f64,
         E: *const f64,
         DF: *const f64,
         EF: *const f64,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sptrfs_64_")]
     pub fn sptrfs_(
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         D: *const f32,
         E: *const f32,
         DF: *const f32,
         EF: *const f32,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zptrfs_64_")]
     pub fn zptrfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         D: *const f64,
         E: *const __BindgenComplex<f64>,
         DF: *const f64,
         EF: *const __BindgenComplex<f64>,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cptsv_64_")]
     pub fn cptsv_(
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         D: *mut f32,
         E: *mut __BindgenComplex<f32>,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dptsv_64_")]
     pub fn dptsv_(
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sptsv_64_")]
     pub fn sptsv_(
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zptsv_64_")]
     pub fn zptsv_(
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         D: *mut f64,
         E: *mut __BindgenComplex<f64>,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cptsvx_64_")]
     pub fn cptsvx_(
         fact: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         D: *const f32,
         E: *const __BindgenComplex<f32>,
         DF: *mut f32,
         EF: *mut __BindgenComplex<f32>,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dptsvx_64_")]
     pub fn dptsvx_(
         fact: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         D: *const f64,
         E: *const f64,
         DF: *mut f64,
         EF: *mut f64,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sptsvx_64_")]
     pub fn sptsvx_(
         fact: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         D: *const f32,
         E: *const f32,
         DF: *mut f32,
         EF: *mut f32,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zptsvx_64_")]
     pub fn zptsvx_(
         fact: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         D: *const f64,
         E: *const __BindgenComplex<f64>,
         DF: *mut f64,
         EF: *mut __BindgenComplex<f64>,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
-    pub fn cpttrf_(n: *const c_int, D: *mut f32, E: *mut __BindgenComplex<f32>, info: *mut c_int);
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpttrf_64_")]
+    pub fn cpttrf_(
+        n: *const lapack_int,
+        D: *mut f32,
+        E: *mut __BindgenComplex<f32>,
+        info: *mut lapack_int,
+    );
 }
 extern "C" {
-    pub fn dpttrf_(n: *const c_int, D: *mut f64, E: *mut f64, info: *mut c_int);
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpttrf_64_")]
+    pub fn dpttrf_(n: *const lapack_int, D: *mut f64, E: *mut f64, info: *mut lapack_int);
 }
 extern "C" {
-    pub fn spttrf_(n: *const c_int, D: *mut f32, E: *mut f32, info: *mut c_int);
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spttrf_64_")]
+    pub fn spttrf_(n: *const lapack_int, D: *mut f32, E: *mut f32, info: *mut lapack_int);
 }
 extern "C" {
-    pub fn zpttrf_(n: *const c_int, D: *mut f64, E: *mut __BindgenComplex<f64>, info: *mut c_int);
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpttrf_64_")]
+    pub fn zpttrf_(
+        n: *const lapack_int,
+        D: *mut f64,
+        E: *mut __BindgenComplex<f64>,
+        info: *mut lapack_int,
+    );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cpttrs_64_")]
     pub fn cpttrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         D: *const f32,
         E: *const __BindgenComplex<f32>,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dpttrs_64_")]
     pub fn dpttrs_(
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         D: *const f64,
         E: *const f64,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "spttrs_64_")]
     pub fn spttrs_(
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         D: *const f32,
         E: *const f32,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zpttrs_64_")]
     pub fn zpttrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         D: *const f64,
         E: *const __BindgenComplex<f64>,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsbev_64_")]
     pub fn dsbev_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssbev_64_")]
     pub fn ssbev_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsbev_2stage_64_")]
     pub fn dsbev_2stage_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssbev_2stage_64_")]
     pub fn ssbev_2stage_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsbevd_64_")]
     pub fn dsbevd_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssbevd_64_")]
     pub fn ssbevd_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsbevd_2stage_64_")]
     pub fn dsbevd_2stage_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssbevd_2stage_64_")]
     pub fn ssbevd_2stage_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsbevx_64_")]
     pub fn dsbevx_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         Q: *mut f64,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssbevx_64_")]
     pub fn ssbevx_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         Q: *mut f32,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsbevx_2stage_64_")]
     pub fn dsbevx_2stage_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         Q: *mut f64,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssbevx_2stage_64_")]
     pub fn ssbevx_2stage_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         Q: *mut f32,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsbgst_64_")]
     pub fn dsbgst_(
         vect: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        ka: *const c_int,
-        kb: *const c_int,
+        n: *const lapack_int,
+        ka: *const lapack_int,
+        kb: *const lapack_int,
         AB: *mut f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         BB: *const f64,
-        ldbb: *const c_int,
+        ldbb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssbgst_64_")]
     pub fn ssbgst_(
         vect: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        ka: *const c_int,
-        kb: *const c_int,
+        n: *const lapack_int,
+        ka: *const lapack_int,
+        kb: *const lapack_int,
         AB: *mut f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         BB: *const f32,
-        ldbb: *const c_int,
+        ldbb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsbgv_64_")]
     pub fn dsbgv_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        ka: *const c_int,
-        kb: *const c_int,
+        n: *const lapack_int,
+        ka: *const lapack_int,
+        kb: *const lapack_int,
         AB: *mut f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         BB: *mut f64,
-        ldbb: *const c_int,
+        ldbb: *const lapack_int,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssbgv_64_")]
     pub fn ssbgv_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        ka: *const c_int,
-        kb: *const c_int,
+        n: *const lapack_int,
+        ka: *const lapack_int,
+        kb: *const lapack_int,
         AB: *mut f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         BB: *mut f32,
-        ldbb: *const c_int,
+        ldbb: *const lapack_int,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsbgvd_64_")]
     pub fn dsbgvd_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        ka: *const c_int,
-        kb: *const c_int,
+        n: *const lapack_int,
+        ka: *const lapack_int,
+        kb: *const lapack_int,
         AB: *mut f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         BB: *mut f64,
-        ldbb: *const c_int,
+        ldbb: *const lapack_int,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssbgvd_64_")]
     pub fn ssbgvd_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        ka: *const c_int,
-        kb: *const c_int,
+        n: *const lapack_int,
+        ka: *const lapack_int,
+        kb: *const lapack_int,
         AB: *mut f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         BB: *mut f32,
-        ldbb: *const c_int,
+        ldbb: *const lapack_int,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsbgvx_64_")]
     pub fn dsbgvx_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        ka: *const c_int,
-        kb: *const c_int,
+        n: *const lapack_int,
+        ka: *const lapack_int,
+        kb: *const lapack_int,
         AB: *mut f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         BB: *mut f64,
-        ldbb: *const c_int,
+        ldbb: *const lapack_int,
         Q: *mut f64,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssbgvx_64_")]
     pub fn ssbgvx_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        ka: *const c_int,
-        kb: *const c_int,
+        n: *const lapack_int,
+        ka: *const lapack_int,
+        kb: *const lapack_int,
         AB: *mut f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         BB: *mut f32,
-        ldbb: *const c_int,
+        ldbb: *const lapack_int,
         Q: *mut f32,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsbtrd_64_")]
     pub fn dsbtrd_(
         vect: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         Q: *mut f64,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssbtrd_64_")]
     pub fn ssbtrd_(
         vect: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *mut f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         Q: *mut f32,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsfrk_64_")]
     pub fn dsfrk_(
         transr: *const c_char,
         uplo: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         alpha: *const f64,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         beta: *const f64,
         C: *mut f64,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssfrk_64_")]
     pub fn ssfrk_(
         transr: *const c_char,
         uplo: *const c_char,
         trans: *const c_char,
-        n: *const c_int,
-        k: *const c_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         alpha: *const f32,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         beta: *const f32,
         C: *mut f32,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cspcon_64_")]
     pub fn cspcon_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f32>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         anorm: *const f32,
         rcond: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dspcon_64_")]
     pub fn dspcon_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const f64,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         anorm: *const f64,
         rcond: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sspcon_64_")]
     pub fn sspcon_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const f32,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         anorm: *const f32,
         rcond: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zspcon_64_")]
     pub fn zspcon_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f64>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         anorm: *const f64,
         rcond: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dspev_64_")]
     pub fn dspev_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut f64,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sspev_64_")]
     pub fn sspev_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut f32,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dspevd_64_")]
     pub fn dspevd_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut f64,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sspevd_64_")]
     pub fn sspevd_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut f32,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dspevx_64_")]
     pub fn dspevx_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut f64,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sspevx_64_")]
     pub fn sspevx_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut f32,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dspgst_64_")]
     pub fn dspgst_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut f64,
         BP: *const f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sspgst_64_")]
     pub fn sspgst_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut f32,
         BP: *const f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dspgv_64_")]
     pub fn dspgv_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut f64,
         BP: *mut f64,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sspgv_64_")]
     pub fn sspgv_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut f32,
         BP: *mut f32,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dspgvd_64_")]
     pub fn dspgvd_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut f64,
         BP: *mut f64,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sspgvd_64_")]
     pub fn sspgvd_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut f32,
         BP: *mut f32,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dspgvx_64_")]
     pub fn dspgvx_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut f64,
         BP: *mut f64,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sspgvx_64_")]
     pub fn sspgvx_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut f32,
         BP: *mut f32,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csprfs_64_")]
     pub fn csprfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const __BindgenComplex<f32>,
         AFP: *const __BindgenComplex<f32>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsprfs_64_")]
     pub fn dsprfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const f64,
         AFP: *const f64,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssprfs_64_")]
     pub fn ssprfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const f32,
         AFP: *const f32,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsprfs_64_")]
     pub fn zsprfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const __BindgenComplex<f64>,
         AFP: *const __BindgenComplex<f64>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cspsv_64_")]
     pub fn cspsv_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *mut __BindgenComplex<f32>,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dspsv_64_")]
     pub fn dspsv_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *mut f64,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sspsv_64_")]
     pub fn sspsv_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *mut f32,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zspsv_64_")]
     pub fn zspsv_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *mut __BindgenComplex<f64>,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cspsvx_64_")]
     pub fn cspsvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const __BindgenComplex<f32>,
         AFP: *mut __BindgenComplex<f32>,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dspsvx_64_")]
     pub fn dspsvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const f64,
         AFP: *mut f64,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sspsvx_64_")]
     pub fn sspsvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const f32,
         AFP: *mut f32,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zspsvx_64_")]
     pub fn zspsvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const __BindgenComplex<f64>,
         AFP: *mut __BindgenComplex<f64>,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsptrd_64_")]
     pub fn dsptrd_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut f64,
         D: *mut f64,
         E: *mut f64,
         tau: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssptrd_64_")]
     pub fn ssptrd_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut f32,
         D: *mut f32,
         E: *mut f32,
         tau: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csptrf_64_")]
     pub fn csptrf_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f32>,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsptrf_64_")]
     pub fn dsptrf_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut f64,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssptrf_64_")]
     pub fn ssptrf_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut f32,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsptrf_64_")]
     pub fn zsptrf_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f64>,
-        ipiv: *mut c_int,
-        info: *mut c_int,
+        ipiv: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csptri_64_")]
     pub fn csptri_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f32>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsptri_64_")]
     pub fn dsptri_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut f64,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssptri_64_")]
     pub fn ssptri_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut f32,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsptri_64_")]
     pub fn zsptri_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f64>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csptrs_64_")]
     pub fn csptrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const __BindgenComplex<f32>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsptrs_64_")]
     pub fn dsptrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const f64,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssptrs_64_")]
     pub fn ssptrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const f32,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsptrs_64_")]
     pub fn zsptrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const __BindgenComplex<f64>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dstebz_64_")]
     pub fn dstebz_(
         range: *const c_char,
         order: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
         D: *const f64,
         E: *const f64,
-        m: *mut c_int,
-        nsplit: *mut c_int,
+        m: *mut lapack_int,
+        nsplit: *mut lapack_int,
         W: *mut f64,
-        IBLOCK: *mut c_int,
-        ISPLIT: *mut c_int,
+        IBLOCK: *mut lapack_int,
+        ISPLIT: *mut lapack_int,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sstebz_64_")]
     pub fn sstebz_(
         range: *const c_char,
         order: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
         D: *const f32,
         E: *const f32,
-        m: *mut c_int,
-        nsplit: *mut c_int,
+        m: *mut lapack_int,
+        nsplit: *mut lapack_int,
         W: *mut f32,
-        IBLOCK: *mut c_int,
-        ISPLIT: *mut c_int,
+        IBLOCK: *mut lapack_int,
+        ISPLIT: *mut lapack_int,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cstedc_64_")]
     pub fn cstedc_(
         compz: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dstedc_64_")]
     pub fn dstedc_(
         compz: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sstedc_64_")]
     pub fn sstedc_(
         compz: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zstedc_64_")]
     pub fn zstedc_(
         compz: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cstegr_64_")]
     pub fn cstegr_(
         jobz: *const c_char,
         range: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
-        ISUPPZ: *mut c_int,
+        ldz: *const lapack_int,
+        ISUPPZ: *mut lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dstegr_64_")]
     pub fn dstegr_(
         jobz: *const c_char,
         range: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
-        ISUPPZ: *mut c_int,
+        ldz: *const lapack_int,
+        ISUPPZ: *mut lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sstegr_64_")]
     pub fn sstegr_(
         jobz: *const c_char,
         range: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
-        ISUPPZ: *mut c_int,
+        ldz: *const lapack_int,
+        ISUPPZ: *mut lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zstegr_64_")]
     pub fn zstegr_(
         jobz: *const c_char,
         range: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
-        ISUPPZ: *mut c_int,
+        ldz: *const lapack_int,
+        ISUPPZ: *mut lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cstein_64_")]
     pub fn cstein_(
-        n: *const c_int,
+        n: *const lapack_int,
         D: *const f32,
         E: *const f32,
-        m: *const c_int,
+        m: *const lapack_int,
         W: *const f32,
-        IBLOCK: *const c_int,
-        ISPLIT: *const c_int,
+        IBLOCK: *const lapack_int,
+        ISPLIT: *const lapack_int,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dstein_64_")]
     pub fn dstein_(
-        n: *const c_int,
+        n: *const lapack_int,
         D: *const f64,
         E: *const f64,
-        m: *const c_int,
+        m: *const lapack_int,
         W: *const f64,
-        IBLOCK: *const c_int,
-        ISPLIT: *const c_int,
+        IBLOCK: *const lapack_int,
+        ISPLIT: *const lapack_int,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sstein_64_")]
     pub fn sstein_(
-        n: *const c_int,
+        n: *const lapack_int,
         D: *const f32,
         E: *const f32,
-        m: *const c_int,
+        m: *const lapack_int,
         W: *const f32,
-        IBLOCK: *const c_int,
-        ISPLIT: *const c_int,
+        IBLOCK: *const lapack_int,
+        ISPLIT: *const lapack_int,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zstein_64_")]
     pub fn zstein_(
-        n: *const c_int,
+        n: *const lapack_int,
         D: *const f64,
         E: *const f64,
-        m: *const c_int,
+        m: *const lapack_int,
         W: *const f64,
-        IBLOCK: *const c_int,
-        ISPLIT: *const c_int,
+        IBLOCK: *const lapack_int,
+        ISPLIT: *const lapack_int,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cstemr_64_")]
     pub fn cstemr_(
         jobz: *const c_char,
         range: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
-        m: *mut c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
-        nzc: *const c_int,
-        ISUPPZ: *mut c_int,
-        tryrac: *mut c_int,
+        ldz: *const lapack_int,
+        nzc: *const lapack_int,
+        ISUPPZ: *mut lapack_int,
+        tryrac: *mut lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dstemr_64_")]
     pub fn dstemr_(
         jobz: *const c_char,
         range: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
-        m: *mut c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
-        nzc: *const c_int,
-        ISUPPZ: *mut c_int,
-        tryrac: *mut c_int,
+        ldz: *const lapack_int,
+        nzc: *const lapack_int,
+        ISUPPZ: *mut lapack_int,
+        tryrac: *mut lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sstemr_64_")]
     pub fn sstemr_(
         jobz: *const c_char,
         range: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
-        m: *mut c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
-        nzc: *const c_int,
-        ISUPPZ: *mut c_int,
-        tryrac: *mut c_int,
+        ldz: *const lapack_int,
+        nzc: *const lapack_int,
+        ISUPPZ: *mut lapack_int,
+        tryrac: *mut lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zstemr_64_")]
     pub fn zstemr_(
         jobz: *const c_char,
         range: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
-        m: *mut c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
-        nzc: *const c_int,
-        ISUPPZ: *mut c_int,
-        tryrac: *mut c_int,
+        ldz: *const lapack_int,
+        nzc: *const lapack_int,
+        ISUPPZ: *mut lapack_int,
+        tryrac: *mut lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csteqr_64_")]
     pub fn csteqr_(
         compz: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsteqr_64_")]
     pub fn dsteqr_(
         compz: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssteqr_64_")]
     pub fn ssteqr_(
         compz: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsteqr_64_")]
     pub fn zsteqr_(
         compz: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
-    pub fn dsterf_(n: *const c_int, D: *mut f64, E: *mut f64, info: *mut c_int);
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsterf_64_")]
+    pub fn dsterf_(n: *const lapack_int, D: *mut f64, E: *mut f64, info: *mut lapack_int);
 }
 extern "C" {
-    pub fn ssterf_(n: *const c_int, D: *mut f32, E: *mut f32, info: *mut c_int);
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssterf_64_")]
+    pub fn ssterf_(n: *const lapack_int, D: *mut f32, E: *mut f32, info: *mut lapack_int);
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dstev_64_")]
     pub fn dstev_(
         jobz: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sstev_64_")]
     pub fn sstev_(
         jobz: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dstevd_64_")]
     pub fn dstevd_(
         jobz: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sstevd_64_")]
     pub fn sstevd_(
         jobz: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dstevr_64_")]
     pub fn dstevr_(
         jobz: *const c_char,
         range: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
-        ISUPPZ: *mut c_int,
+        ldz: *const lapack_int,
+        ISUPPZ: *mut lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sstevr_64_")]
     pub fn sstevr_(
         jobz: *const c_char,
         range: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
-        ISUPPZ: *mut c_int,
+        ldz: *const lapack_int,
+        ISUPPZ: *mut lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dstevx_64_")]
     pub fn dstevx_(
         jobz: *const c_char,
         range: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "sstevx_64_")]
     pub fn sstevx_(
         jobz: *const c_char,
         range: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csycon_64_")]
     pub fn csycon_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         anorm: *const f32,
         rcond: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsycon_64_")]
     pub fn dsycon_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         anorm: *const f64,
         rcond: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssycon_64_")]
     pub fn ssycon_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         anorm: *const f32,
         rcond: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsycon_64_")]
     pub fn zsycon_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         anorm: *const f64,
         rcond: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csycon_3_64_")]
     pub fn csycon_3_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *const __BindgenComplex<f32>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         anorm: *const f32,
         rcond: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsycon_3_64_")]
     pub fn dsycon_3_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *const f64,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         anorm: *const f64,
         rcond: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssycon_3_64_")]
     pub fn ssycon_3_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *const f32,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         anorm: *const f32,
         rcond: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsycon_3_64_")]
     pub fn zsycon_3_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *const __BindgenComplex<f64>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         anorm: *const f64,
         rcond: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csyconv_64_")]
     pub fn csyconv_(
         uplo: *const c_char,
         way: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         E: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsyconv_64_")]
     pub fn dsyconv_(
         uplo: *const c_char,
         way: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         E: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssyconv_64_")]
     pub fn ssyconv_(
         uplo: *const c_char,
         way: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         E: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsyconv_64_")]
     pub fn zsyconv_(
         uplo: *const c_char,
         way: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         E: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csyequb_64_")]
     pub fn csyequb_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f32,
         scond: *mut f32,
         amax: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsyequb_64_")]
     pub fn dsyequb_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f64,
         scond: *mut f64,
         amax: *mut f64,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssyequb_64_")]
     pub fn ssyequb_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f32,
         scond: *mut f32,
         amax: *mut f32,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsyequb_64_")]
     pub fn zsyequb_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         S: *mut f64,
         scond: *mut f64,
         amax: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsyev_64_")]
     pub fn dsyev_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         W: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssyev_64_")]
     pub fn ssyev_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         W: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsyev_2stage_64_")]
     pub fn dsyev_2stage_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         W: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssyev_2stage_64_")]
     pub fn ssyev_2stage_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         W: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsyevd_64_")]
     pub fn dsyevd_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         W: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssyevd_64_")]
     pub fn ssyevd_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         W: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsyevd_2stage_64_")]
     pub fn dsyevd_2stage_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         W: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssyevd_2stage_64_")]
     pub fn ssyevd_2stage_(
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         W: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsyevr_64_")]
     pub fn dsyevr_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
-        ISUPPZ: *mut c_int,
+        ldz: *const lapack_int,
+        ISUPPZ: *mut lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssyevr_64_")]
     pub fn ssyevr_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
-        ISUPPZ: *mut c_int,
+        ldz: *const lapack_int,
+        ISUPPZ: *mut lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsyevr_2stage_64_")]
     pub fn dsyevr_2stage_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
-        ISUPPZ: *mut c_int,
+        ldz: *const lapack_int,
+        ISUPPZ: *mut lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssyevr_2stage_64_")]
     pub fn ssyevr_2stage_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
-        ISUPPZ: *mut c_int,
+        ldz: *const lapack_int,
+        ISUPPZ: *mut lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsyevx_64_")]
     pub fn dsyevx_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssyevx_64_")]
     pub fn ssyevx_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsyevx_2stage_64_")]
     pub fn dsyevx_2stage_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssyevx_2stage_64_")]
     pub fn ssyevx_2stage_(
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsygst_64_")]
     pub fn dsygst_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssygst_64_")]
     pub fn ssygst_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsygv_64_")]
     pub fn dsygv_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         W: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssygv_64_")]
     pub fn ssygv_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         W: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsygv_2stage_64_")]
     pub fn dsygv_2stage_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         W: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssygv_2stage_64_")]
     pub fn ssygv_2stage_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         W: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsygvd_64_")]
     pub fn dsygvd_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         W: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssygvd_64_")]
     pub fn ssygvd_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         W: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsygvx_64_")]
     pub fn dsygvx_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         vl: *const f64,
         vu: *const f64,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f64,
         Z: *mut f64,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssygvx_64_")]
     pub fn ssygvx_(
-        itype: *const c_int,
+        itype: *const lapack_int,
         jobz: *const c_char,
         range: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         vl: *const f32,
         vu: *const f32,
-        il: *const c_int,
-        iu: *const c_int,
+        il: *const lapack_int,
+        iu: *const lapack_int,
         abstol: *const f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         W: *mut f32,
         Z: *mut f32,
-        ldz: *const c_int,
+        ldz: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        IFAIL: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        IFAIL: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csyr_64_")]
     pub fn csyr_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         alpha: *const __BindgenComplex<f32>,
         X: *const __BindgenComplex<f32>,
-        incx: *const c_int,
+        incx: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsyr_64_")]
     pub fn zsyr_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         alpha: *const __BindgenComplex<f64>,
         X: *const __BindgenComplex<f64>,
-        incx: *const c_int,
+        incx: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csyrfs_64_")]
     pub fn csyrfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const __BindgenComplex<f32>,
-        ldaf: *const c_int,
-        ipiv: *const c_int,
+        ldaf: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsyrfs_64_")]
     pub fn dsyrfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const f64,
-        ldaf: *const c_int,
-        ipiv: *const c_int,
+        ldaf: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssyrfs_64_")]
     pub fn ssyrfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const f32,
-        ldaf: *const c_int,
-        ipiv: *const c_int,
+        ldaf: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsyrfs_64_")]
     pub fn zsyrfs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const __BindgenComplex<f64>,
-        ldaf: *const c_int,
-        ipiv: *const c_int,
+        ldaf: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csyrfsx_64_")]
     pub fn csyrfsx_(
         uplo: *const c_char,
         equed: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const __BindgenComplex<f32>,
-        ldaf: *const c_int,
-        ipiv: *const c_int,
+        ldaf: *const lapack_int,
+        ipiv: *const lapack_int,
         S: *mut f32,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         berr: *mut f32,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f32,
         err_bnds_comp: *mut f32,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsyrfsx_64_")]
     pub fn dsyrfsx_(
         uplo: *const c_char,
         equed: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const f64,
-        ldaf: *const c_int,
-        ipiv: *const c_int,
+        ldaf: *const lapack_int,
+        ipiv: *const lapack_int,
         S: *mut f64,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         berr: *mut f64,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f64,
         err_bnds_comp: *mut f64,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssyrfsx_64_")]
     pub fn ssyrfsx_(
         uplo: *const c_char,
         equed: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const f32,
-        ldaf: *const c_int,
-        ipiv: *const c_int,
+        ldaf: *const lapack_int,
+        ipiv: *const lapack_int,
         S: *mut f32,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         berr: *mut f32,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f32,
         err_bnds_comp: *mut f32,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsyrfsx_64_")]
     pub fn zsyrfsx_(
         uplo: *const c_char,
         equed: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *const __BindgenComplex<f64>,
-        ldaf: *const c_int,
-        ipiv: *const c_int,
+        ldaf: *const lapack_int,
+        ipiv: *const lapack_int,
         S: *mut f64,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         berr: *mut f64,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f64,
         err_bnds_comp: *mut f64,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csysv_64_")]
     pub fn csysv_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsysv_64_")]
     pub fn dsysv_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssysv_64_")]
     pub fn ssysv_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsysv_64_")]
     pub fn zsysv_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csysv_aa_64_")]
     pub fn csysv_aa_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsysv_aa_64_")]
     pub fn dsysv_aa_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssysv_aa_64_")]
     pub fn ssysv_aa_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsysv_aa_64_")]
     pub fn zsysv_aa_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csysv_aa_2stage_64_")]
     pub fn csysv_aa_2stage_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         TB: *mut __BindgenComplex<f32>,
-        ltb: *const c_int,
-        ipiv: *mut c_int,
-        ipiv2: *mut c_int,
+        ltb: *const lapack_int,
+        ipiv: *mut lapack_int,
+        ipiv2: *mut lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsysv_aa_2stage_64_")]
     pub fn dsysv_aa_2stage_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         TB: *mut f64,
-        ltb: *const c_int,
-        ipiv: *mut c_int,
-        ipiv2: *mut c_int,
+        ltb: *const lapack_int,
+        ipiv: *mut lapack_int,
+        ipiv2: *mut lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssysv_aa_2stage_64_")]
     pub fn ssysv_aa_2stage_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         TB: *mut f32,
-        ltb: *const c_int,
-        ipiv: *mut c_int,
-        ipiv2: *mut c_int,
+        ltb: *const lapack_int,
+        ipiv: *mut lapack_int,
+        ipiv2: *mut lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsysv_aa_2stage_64_")]
     pub fn zsysv_aa_2stage_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         TB: *mut __BindgenComplex<f64>,
-        ltb: *const c_int,
-        ipiv: *mut c_int,
-        ipiv2: *mut c_int,
+        ltb: *const lapack_int,
+        ipiv: *mut lapack_int,
+        ipiv2: *mut lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csysv_rk_64_")]
     pub fn csysv_rk_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *mut __BindgenComplex<f32>,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsysv_rk_64_")]
     pub fn dsysv_rk_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *mut f64,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssysv_rk_64_")]
     pub fn ssysv_rk_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *mut f32,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsysv_rk_64_")]
     pub fn zsysv_rk_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *mut __BindgenComplex<f64>,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csysv_rook_64_")]
     pub fn csysv_rook_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsysv_rook_64_")]
     pub fn dsysv_rook_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssysv_rook_64_")]
     pub fn ssysv_rook_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsysv_rook_64_")]
     pub fn zsysv_rook_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csysvx_64_")]
     pub fn csysvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut __BindgenComplex<f32>,
-        ldaf: *const c_int,
-        ipiv: *mut c_int,
+        ldaf: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsysvx_64_")]
     pub fn dsysvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut f64,
-        ldaf: *const c_int,
-        ipiv: *mut c_int,
+        ldaf: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssysvx_64_")]
     pub fn ssysvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut f32,
-        ldaf: *const c_int,
-        ipiv: *mut c_int,
+        ldaf: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsysvx_64_")]
     pub fn zsysvx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut __BindgenComplex<f64>,
-        ldaf: *const c_int,
-        ipiv: *mut c_int,
+        ldaf: *const lapack_int,
+        ipiv: *mut lapack_int,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csysvxx_64_")]
     pub fn csysvxx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut __BindgenComplex<f32>,
-        ldaf: *const c_int,
-        ipiv: *mut c_int,
+        ldaf: *const lapack_int,
+        ipiv: *mut lapack_int,
         equed: *mut c_char,
         S: *mut f32,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         rpvgrw: *mut f32,
         berr: *mut f32,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f32,
         err_bnds_comp: *mut f32,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsysvxx_64_")]
     pub fn dsysvxx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut f64,
-        ldaf: *const c_int,
-        ipiv: *mut c_int,
+        ldaf: *const lapack_int,
+        ipiv: *mut lapack_int,
         equed: *mut c_char,
         S: *mut f64,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         rpvgrw: *mut f64,
         berr: *mut f64,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f64,
         err_bnds_comp: *mut f64,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssysvxx_64_")]
     pub fn ssysvxx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut f32,
-        ldaf: *const c_int,
-        ipiv: *mut c_int,
+        ldaf: *const lapack_int,
+        ipiv: *mut lapack_int,
         equed: *mut c_char,
         S: *mut f32,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f32,
         rpvgrw: *mut f32,
         berr: *mut f32,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f32,
         err_bnds_comp: *mut f32,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsysvxx_64_")]
     pub fn zsysvxx_(
         fact: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AF: *mut __BindgenComplex<f64>,
-        ldaf: *const c_int,
-        ipiv: *mut c_int,
+        ldaf: *const lapack_int,
+        ipiv: *mut lapack_int,
         equed: *mut c_char,
         S: *mut f64,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *mut __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         rcond: *mut f64,
         rpvgrw: *mut f64,
         berr: *mut f64,
-        n_err_bnds: *const c_int,
+        n_err_bnds: *const lapack_int,
         err_bnds_norm: *mut f64,
         err_bnds_comp: *mut f64,
-        nparams: *const c_int,
+        nparams: *const lapack_int,
         params: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csyswapr_64_")]
     pub fn csyswapr_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        i1: *const c_int,
-        i2: *const c_int,
+        lda: *const lapack_int,
+        i1: *const lapack_int,
+        i2: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsyswapr_64_")]
     pub fn dsyswapr_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        i1: *const c_int,
-        i2: *const c_int,
+        lda: *const lapack_int,
+        i1: *const lapack_int,
+        i2: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssyswapr_64_")]
     pub fn ssyswapr_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        i1: *const c_int,
-        i2: *const c_int,
+        lda: *const lapack_int,
+        i1: *const lapack_int,
+        i2: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsyswapr_64_")]
     pub fn zsyswapr_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        i1: *const c_int,
-        i2: *const c_int,
+        lda: *const lapack_int,
+        i1: *const lapack_int,
+        i2: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsytrd_64_")]
     pub fn dsytrd_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         tau: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssytrd_64_")]
     pub fn ssytrd_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         tau: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsytrd_2stage_64_")]
     pub fn dsytrd_2stage_(
         vect: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         D: *mut f64,
         E: *mut f64,
         tau: *mut f64,
         HOUS2: *mut f64,
-        lhous2: *const c_int,
+        lhous2: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssytrd_2stage_64_")]
     pub fn ssytrd_2stage_(
         vect: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         D: *mut f32,
         E: *mut f32,
         tau: *mut f32,
         HOUS2: *mut f32,
-        lhous2: *const c_int,
+        lhous2: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csytrf_64_")]
     pub fn csytrf_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsytrf_64_")]
     pub fn dsytrf_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssytrf_64_")]
     pub fn ssytrf_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsytrf_64_")]
     pub fn zsytrf_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csytrf_aa_64_")]
     pub fn csytrf_aa_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsytrf_aa_64_")]
     pub fn dsytrf_aa_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssytrf_aa_64_")]
     pub fn ssytrf_aa_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsytrf_aa_64_")]
     pub fn zsytrf_aa_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csytrf_aa_2stage_64_")]
     pub fn csytrf_aa_2stage_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         TB: *mut __BindgenComplex<f32>,
-        ltb: *const c_int,
-        ipiv: *mut c_int,
-        ipiv2: *mut c_int,
+        ltb: *const lapack_int,
+        ipiv: *mut lapack_int,
+        ipiv2: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsytrf_aa_2stage_64_")]
     pub fn dsytrf_aa_2stage_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         TB: *mut f64,
-        ltb: *const c_int,
-        ipiv: *mut c_int,
-        ipiv2: *mut c_int,
+        ltb: *const lapack_int,
+        ipiv: *mut lapack_int,
+        ipiv2: *mut lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssytrf_aa_2stage_64_")]
     pub fn ssytrf_aa_2stage_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         TB: *mut f32,
-        ltb: *const c_int,
-        ipiv: *mut c_int,
-        ipiv2: *mut c_int,
+        ltb: *const lapack_int,
+        ipiv: *mut lapack_int,
+        ipiv2: *mut lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsytrf_aa_2stage_64_")]
     pub fn zsytrf_aa_2stage_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         TB: *mut __BindgenComplex<f64>,
-        ltb: *const c_int,
-        ipiv: *mut c_int,
-        ipiv2: *mut c_int,
+        ltb: *const lapack_int,
+        ipiv: *mut lapack_int,
+        ipiv2: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csytrf_rk_64_")]
     pub fn csytrf_rk_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *mut __BindgenComplex<f32>,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsytrf_rk_64_")]
     pub fn dsytrf_rk_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *mut f64,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssytrf_rk_64_")]
     pub fn ssytrf_rk_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *mut f32,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsytrf_rk_64_")]
     pub fn zsytrf_rk_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *mut __BindgenComplex<f64>,
-        ipiv: *mut c_int,
+        ipiv: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csytrf_rook_64_")]
     pub fn csytrf_rook_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsytrf_rook_64_")]
     pub fn dsytrf_rook_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssytrf_rook_64_")]
     pub fn ssytrf_rook_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsytrf_rook_64_")]
     pub fn zsytrf_rook_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *mut c_int,
+        lda: *const lapack_int,
+        ipiv: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csytri_64_")]
     pub fn csytri_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsytri_64_")]
     pub fn dsytri_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssytri_64_")]
     pub fn ssytri_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsytri_64_")]
     pub fn zsytri_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csytri2_64_")]
     pub fn csytri2_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsytri2_64_")]
     pub fn dsytri2_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssytri2_64_")]
     pub fn ssytri2_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsytri2_64_")]
     pub fn zsytri2_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csytri2x_64_")]
     pub fn csytri2x_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        nb: *const c_int,
-        info: *mut c_int,
+        nb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsytri2x_64_")]
     pub fn dsytri2x_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         work: *mut f64,
-        nb: *const c_int,
-        info: *mut c_int,
+        nb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssytri2x_64_")]
     pub fn ssytri2x_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         work: *mut f32,
-        nb: *const c_int,
-        info: *mut c_int,
+        nb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsytri2x_64_")]
     pub fn zsytri2x_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        nb: *const c_int,
-        info: *mut c_int,
+        nb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csytri_3_64_")]
     pub fn csytri_3_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *const __BindgenComplex<f32>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsytri_3_64_")]
     pub fn dsytri_3_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *const f64,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssytri_3_64_")]
     pub fn ssytri_3_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *const f32,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsytri_3_64_")]
     pub fn zsytri_3_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *const __BindgenComplex<f64>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csytrs_64_")]
     pub fn csytrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsytrs_64_")]
     pub fn dsytrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssytrs_64_")]
     pub fn ssytrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsytrs_64_")]
     pub fn zsytrs_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csytrs2_64_")]
     pub fn csytrs2_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsytrs2_64_")]
     pub fn dsytrs2_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssytrs2_64_")]
     pub fn ssytrs2_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsytrs2_64_")]
     pub fn zsytrs2_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csytrs_3_64_")]
     pub fn csytrs_3_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *const __BindgenComplex<f32>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsytrs_3_64_")]
     pub fn dsytrs_3_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *const f64,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssytrs_3_64_")]
     pub fn ssytrs_3_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *const f32,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsytrs_3_64_")]
     pub fn zsytrs_3_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         E: *const __BindgenComplex<f64>,
-        ipiv: *const c_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csytrs_aa_64_")]
     pub fn csytrs_aa_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsytrs_aa_64_")]
     pub fn dsytrs_aa_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssytrs_aa_64_")]
     pub fn ssytrs_aa_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsytrs_aa_64_")]
     pub fn zsytrs_aa_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csytrs_aa_2stage_64_")]
     pub fn csytrs_aa_2stage_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         TB: *mut __BindgenComplex<f32>,
-        ltb: *const c_int,
-        ipiv: *const c_int,
-        ipiv2: *const c_int,
+        ltb: *const lapack_int,
+        ipiv: *const lapack_int,
+        ipiv2: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsytrs_aa_2stage_64_")]
     pub fn dsytrs_aa_2stage_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         TB: *mut f64,
-        ltb: *const c_int,
-        ipiv: *const c_int,
-        ipiv2: *const c_int,
+        ltb: *const lapack_int,
+        ipiv: *const lapack_int,
+        ipiv2: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssytrs_aa_2stage_64_")]
     pub fn ssytrs_aa_2stage_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         TB: *mut f32,
-        ltb: *const c_int,
-        ipiv: *const c_int,
-        ipiv2: *const c_int,
+        ltb: *const lapack_int,
+        ipiv: *const lapack_int,
+        ipiv2: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsytrs_aa_2stage_64_")]
     pub fn zsytrs_aa_2stage_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         TB: *mut __BindgenComplex<f64>,
-        ltb: *const c_int,
-        ipiv: *const c_int,
-        ipiv2: *const c_int,
+        ltb: *const lapack_int,
+        ipiv: *const lapack_int,
+        ipiv2: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "csytrs_rook_64_")]
     pub fn csytrs_rook_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsytrs_rook_64_")]
     pub fn dsytrs_rook_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ssytrs_rook_64_")]
     pub fn ssytrs_rook_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zsytrs_rook_64_")]
     pub fn zsytrs_rook_(
         uplo: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
-        ipiv: *const c_int,
+        lda: *const lapack_int,
+        ipiv: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctbcon_64_")]
     pub fn ctbcon_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *const __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         rcond: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtbcon_64_")]
     pub fn dtbcon_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *const f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         rcond: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stbcon_64_")]
     pub fn stbcon_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *const f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         rcond: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztbcon_64_")]
     pub fn ztbcon_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
         AB: *const __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         rcond: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctbrfs_64_")]
     pub fn ctbrfs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *const __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtbrfs_64_")]
     pub fn dtbrfs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *const f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stbrfs_64_")]
     pub fn stbrfs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *const f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztbrfs_64_")]
     pub fn ztbrfs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *const __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctbtrs_64_")]
     pub fn ctbtrs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const __BindgenComplex<f32>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtbtrs_64_")]
     pub fn dtbtrs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const f64,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stbtrs_64_")]
     pub fn stbtrs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const f32,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztbtrs_64_")]
     pub fn ztbtrs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        kd: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        kd: *const lapack_int,
+        nrhs: *const lapack_int,
         AB: *const __BindgenComplex<f64>,
-        ldab: *const c_int,
+        ldab: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctfsm_64_")]
     pub fn ctfsm_(
         transr: *const c_char,
         side: *const c_char,
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         alpha: *const __BindgenComplex<f32>,
         A: *const __BindgenComplex<f32>,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtfsm_64_")]
     pub fn dtfsm_(
         transr: *const c_char,
         side: *const c_char,
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         alpha: *const f64,
         A: *const f64,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stfsm_64_")]
     pub fn stfsm_(
         transr: *const c_char,
         side: *const c_char,
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         alpha: *const f32,
         A: *const f32,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztfsm_64_")]
     pub fn ztfsm_(
         transr: *const c_char,
         side: *const c_char,
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         alpha: *const __BindgenComplex<f64>,
         A: *const __BindgenComplex<f64>,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctftri_64_")]
     pub fn ctftri_(
         transr: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtftri_64_")]
     pub fn dtftri_(
         transr: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stftri_64_")]
     pub fn stftri_(
         transr: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztftri_64_")]
     pub fn ztftri_(
         transr: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctfttp_64_")]
     pub fn ctfttp_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         ARF: *const __BindgenComplex<f32>,
         AP: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtfttp_64_")]
     pub fn dtfttp_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         ARF: *const f64,
         AP: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stfttp_64_")]
     pub fn stfttp_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         ARF: *const f32,
         AP: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztfttp_64_")]
     pub fn ztfttp_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         ARF: *const __BindgenComplex<f64>,
         AP: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctfttr_64_")]
     pub fn ctfttr_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         ARF: *const __BindgenComplex<f32>,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtfttr_64_")]
     pub fn dtfttr_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         ARF: *const f64,
         A: *mut f64,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stfttr_64_")]
     pub fn stfttr_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         ARF: *const f32,
         A: *mut f32,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztfttr_64_")]
     pub fn ztfttr_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         ARF: *const __BindgenComplex<f64>,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctgevc_64_")]
     pub fn ctgevc_(
         side: *const c_char,
         howmny: *const c_char,
-        select: *const c_int,
-        n: *const c_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         S: *const __BindgenComplex<f32>,
-        lds: *const c_int,
+        lds: *const lapack_int,
         P: *const __BindgenComplex<f32>,
-        ldp: *const c_int,
+        ldp: *const lapack_int,
         VL: *mut __BindgenComplex<f32>,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut __BindgenComplex<f32>,
-        ldvr: *const c_int,
-        mm: *const c_int,
-        m: *mut c_int,
+        ldvr: *const lapack_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtgevc_64_")]
     pub fn dtgevc_(
         side: *const c_char,
         howmny: *const c_char,
-        select: *const c_int,
-        n: *const c_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         S: *const f64,
-        lds: *const c_int,
+        lds: *const lapack_int,
         P: *const f64,
-        ldp: *const c_int,
+        ldp: *const lapack_int,
         VL: *mut f64,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut f64,
-        ldvr: *const c_int,
-        mm: *const c_int,
-        m: *mut c_int,
+        ldvr: *const lapack_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stgevc_64_")]
     pub fn stgevc_(
         side: *const c_char,
         howmny: *const c_char,
-        select: *const c_int,
-        n: *const c_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         S: *const f32,
-        lds: *const c_int,
+        lds: *const lapack_int,
         P: *const f32,
-        ldp: *const c_int,
+        ldp: *const lapack_int,
         VL: *mut f32,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut f32,
-        ldvr: *const c_int,
-        mm: *const c_int,
-        m: *mut c_int,
+        ldvr: *const lapack_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztgevc_64_")]
     pub fn ztgevc_(
         side: *const c_char,
         howmny: *const c_char,
-        select: *const c_int,
-        n: *const c_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         S: *const __BindgenComplex<f64>,
-        lds: *const c_int,
+        lds: *const lapack_int,
         P: *const __BindgenComplex<f64>,
-        ldp: *const c_int,
+        ldp: *const lapack_int,
         VL: *mut __BindgenComplex<f64>,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut __BindgenComplex<f64>,
-        ldvr: *const c_int,
-        mm: *const c_int,
-        m: *mut c_int,
+        ldvr: *const lapack_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctgexc_64_")]
     pub fn ctgexc_(
-        wantq: *const c_int,
-        wantz: *const c_int,
-        n: *const c_int,
+        wantq: *const lapack_int,
+        wantz: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         Q: *mut __BindgenComplex<f32>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
-        ifst: *const c_int,
-        ilst: *mut c_int,
-        info: *mut c_int,
+        ldz: *const lapack_int,
+        ifst: *const lapack_int,
+        ilst: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtgexc_64_")]
     pub fn dtgexc_(
-        wantq: *const c_int,
-        wantz: *const c_int,
-        n: *const c_int,
+        wantq: *const lapack_int,
+        wantz: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         Q: *mut f64,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         Z: *mut f64,
-        ldz: *const c_int,
-        ifst: *mut c_int,
-        ilst: *mut c_int,
+        ldz: *const lapack_int,
+        ifst: *mut lapack_int,
+        ilst: *mut lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stgexc_64_")]
     pub fn stgexc_(
-        wantq: *const c_int,
-        wantz: *const c_int,
-        n: *const c_int,
+        wantq: *const lapack_int,
+        wantz: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         Q: *mut f32,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         Z: *mut f32,
-        ldz: *const c_int,
-        ifst: *mut c_int,
-        ilst: *mut c_int,
+        ldz: *const lapack_int,
+        ifst: *mut lapack_int,
+        ilst: *mut lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztgexc_64_")]
     pub fn ztgexc_(
-        wantq: *const c_int,
-        wantz: *const c_int,
-        n: *const c_int,
+        wantq: *const lapack_int,
+        wantz: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         Q: *mut __BindgenComplex<f64>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
-        ifst: *const c_int,
-        ilst: *mut c_int,
-        info: *mut c_int,
+        ldz: *const lapack_int,
+        ifst: *const lapack_int,
+        ilst: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctgsen_64_")]
     pub fn ctgsen_(
-        ijob: *const c_int,
-        wantq: *const c_int,
-        wantz: *const c_int,
-        select: *const c_int,
-        n: *const c_int,
+        ijob: *const lapack_int,
+        wantq: *const lapack_int,
+        wantz: *const lapack_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alpha: *mut __BindgenComplex<f32>,
         beta: *mut __BindgenComplex<f32>,
         Q: *mut __BindgenComplex<f32>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         Z: *mut __BindgenComplex<f32>,
-        ldz: *const c_int,
-        m: *mut c_int,
+        ldz: *const lapack_int,
+        m: *mut lapack_int,
         pl: *mut f32,
         pr: *mut f32,
         DIF: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtgsen_64_")]
     pub fn dtgsen_(
-        ijob: *const c_int,
-        wantq: *const c_int,
-        wantz: *const c_int,
-        select: *const c_int,
-        n: *const c_int,
+        ijob: *const lapack_int,
+        wantq: *const lapack_int,
+        wantz: *const lapack_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alphar: *mut f64,
         alphai: *mut f64,
         beta: *mut f64,
         Q: *mut f64,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         Z: *mut f64,
-        ldz: *const c_int,
-        m: *mut c_int,
+        ldz: *const lapack_int,
+        m: *mut lapack_int,
         pl: *mut f64,
         pr: *mut f64,
         DIF: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stgsen_64_")]
     pub fn stgsen_(
-        ijob: *const c_int,
-        wantq: *const c_int,
-        wantz: *const c_int,
-        select: *const c_int,
-        n: *const c_int,
+        ijob: *const lapack_int,
+        wantq: *const lapack_int,
+        wantz: *const lapack_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alphar: *mut f32,
         alphai: *mut f32,
         beta: *mut f32,
         Q: *mut f32,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         Z: *mut f32,
-        ldz: *const c_int,
-        m: *mut c_int,
+        ldz: *const lapack_int,
+        m: *mut lapack_int,
         pl: *mut f32,
         pr: *mut f32,
         DIF: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztgsen_64_")]
     pub fn ztgsen_(
-        ijob: *const c_int,
-        wantq: *const c_int,
-        wantz: *const c_int,
-        select: *const c_int,
-        n: *const c_int,
+        ijob: *const lapack_int,
+        wantq: *const lapack_int,
+        wantz: *const lapack_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         alpha: *mut __BindgenComplex<f64>,
         beta: *mut __BindgenComplex<f64>,
         Q: *mut __BindgenComplex<f64>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         Z: *mut __BindgenComplex<f64>,
-        ldz: *const c_int,
-        m: *mut c_int,
+        ldz: *const lapack_int,
+        m: *mut lapack_int,
         pl: *mut f64,
         pr: *mut f64,
         DIF: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctgsja_64_")]
     pub fn ctgsja_(
         jobu: *const c_char,
         jobv: *const c_char,
         jobq: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        l: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        l: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         tola: *const f32,
         tolb: *const f32,
         alpha: *mut f32,
         beta: *mut f32,
         U: *mut __BindgenComplex<f32>,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         V: *mut __BindgenComplex<f32>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         Q: *mut __BindgenComplex<f32>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        ncycle: *mut c_int,
-        info: *mut c_int,
+        ncycle: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtgsja_64_")]
     pub fn dtgsja_(
         jobu: *const c_char,
         jobv: *const c_char,
         jobq: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        l: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        l: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         tola: *const f64,
         tolb: *const f64,
         alpha: *mut f64,
         beta: *mut f64,
         U: *mut f64,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         V: *mut f64,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         Q: *mut f64,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         work: *mut f64,
-        ncycle: *mut c_int,
-        info: *mut c_int,
+        ncycle: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stgsja_64_")]
     pub fn stgsja_(
         jobu: *const c_char,
         jobv: *const c_char,
         jobq: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        l: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        l: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         tola: *const f32,
         tolb: *const f32,
         alpha: *mut f32,
         beta: *mut f32,
         U: *mut f32,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         V: *mut f32,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         Q: *mut f32,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         work: *mut f32,
-        ncycle: *mut c_int,
-        info: *mut c_int,
+        ncycle: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztgsja_64_")]
     pub fn ztgsja_(
         jobu: *const c_char,
         jobv: *const c_char,
         jobq: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        l: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        l: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         tola: *const f64,
         tolb: *const f64,
         alpha: *mut f64,
         beta: *mut f64,
         U: *mut __BindgenComplex<f64>,
-        ldu: *const c_int,
+        ldu: *const lapack_int,
         V: *mut __BindgenComplex<f64>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         Q: *mut __BindgenComplex<f64>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        ncycle: *mut c_int,
-        info: *mut c_int,
+        ncycle: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctgsna_64_")]
     pub fn ctgsna_(
         job: *const c_char,
         howmny: *const c_char,
-        select: *const c_int,
-        n: *const c_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         VL: *const __BindgenComplex<f32>,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *const __BindgenComplex<f32>,
-        ldvr: *const c_int,
+        ldvr: *const lapack_int,
         S: *mut f32,
         DIF: *mut f32,
-        mm: *const c_int,
-        m: *mut c_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtgsna_64_")]
     pub fn dtgsna_(
         job: *const c_char,
         howmny: *const c_char,
-        select: *const c_int,
-        n: *const c_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         VL: *const f64,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *const f64,
-        ldvr: *const c_int,
+        ldvr: *const lapack_int,
         S: *mut f64,
         DIF: *mut f64,
-        mm: *const c_int,
-        m: *mut c_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stgsna_64_")]
     pub fn stgsna_(
         job: *const c_char,
         howmny: *const c_char,
-        select: *const c_int,
-        n: *const c_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         VL: *const f32,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *const f32,
-        ldvr: *const c_int,
+        ldvr: *const lapack_int,
         S: *mut f32,
         DIF: *mut f32,
-        mm: *const c_int,
-        m: *mut c_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztgsna_64_")]
     pub fn ztgsna_(
         job: *const c_char,
         howmny: *const c_char,
-        select: *const c_int,
-        n: *const c_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         VL: *const __BindgenComplex<f64>,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *const __BindgenComplex<f64>,
-        ldvr: *const c_int,
+        ldvr: *const lapack_int,
         S: *mut f64,
         DIF: *mut f64,
-        mm: *const c_int,
-        m: *mut c_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctgsyl_64_")]
     pub fn ctgsyl_(
         trans: *const c_char,
-        ijob: *const c_int,
-        m: *const c_int,
-        n: *const c_int,
+        ijob: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         C: *mut __BindgenComplex<f32>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         D: *const __BindgenComplex<f32>,
-        ldd: *const c_int,
+        ldd: *const lapack_int,
         E: *const __BindgenComplex<f32>,
-        lde: *const c_int,
+        lde: *const lapack_int,
         F: *mut __BindgenComplex<f32>,
-        ldf: *const c_int,
+        ldf: *const lapack_int,
         dif: *mut f32,
         scale: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtgsyl_64_")]
     pub fn dtgsyl_(
         trans: *const c_char,
-        ijob: *const c_int,
-        m: *const c_int,
-        n: *const c_int,
+        ijob: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         C: *mut f64,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         D: *const f64,
-        ldd: *const c_int,
+        ldd: *const lapack_int,
         E: *const f64,
-        lde: *const c_int,
+        lde: *const lapack_int,
         F: *mut f64,
-        ldf: *const c_int,
+        ldf: *const lapack_int,
         dif: *mut f64,
         scale: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stgsyl_64_")]
     pub fn stgsyl_(
         trans: *const c_char,
-        ijob: *const c_int,
-        m: *const c_int,
-        n: *const c_int,
+        ijob: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         C: *mut f32,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         D: *const f32,
-        ldd: *const c_int,
+        ldd: *const lapack_int,
         E: *const f32,
-        lde: *const c_int,
+        lde: *const lapack_int,
         F: *mut f32,
-        ldf: *const c_int,
+        ldf: *const lapack_int,
         dif: *mut f32,
         scale: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztgsyl_64_")]
     pub fn ztgsyl_(
         trans: *const c_char,
-        ijob: *const c_int,
-        m: *const c_int,
-        n: *const c_int,
+        ijob: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         C: *mut __BindgenComplex<f64>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         D: *const __BindgenComplex<f64>,
-        ldd: *const c_int,
+        ldd: *const lapack_int,
         E: *const __BindgenComplex<f64>,
-        lde: *const c_int,
+        lde: *const lapack_int,
         F: *mut __BindgenComplex<f64>,
-        ldf: *const c_int,
+        ldf: *const lapack_int,
         dif: *mut f64,
         scale: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctpcon_64_")]
     pub fn ctpcon_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f32>,
         rcond: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtpcon_64_")]
     pub fn dtpcon_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const f64,
         rcond: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stpcon_64_")]
     pub fn stpcon_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const f32,
         rcond: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztpcon_64_")]
     pub fn ztpcon_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f64>,
         rcond: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctplqt_64_")]
     pub fn ctplqt_(
-        m: *const c_int,
-        n: *const c_int,
-        l: *const c_int,
-        mb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        l: *const lapack_int,
+        mb: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         T: *mut __BindgenComplex<f32>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtplqt_64_")]
     pub fn dtplqt_(
-        m: *const c_int,
-        n: *const c_int,
-        l: *const c_int,
-        mb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        l: *const lapack_int,
+        mb: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         T: *mut f64,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stplqt_64_")]
     pub fn stplqt_(
-        m: *const c_int,
-        n: *const c_int,
-        l: *const c_int,
-        mb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        l: *const lapack_int,
+        mb: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         T: *mut f32,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztplqt_64_")]
     pub fn ztplqt_(
-        m: *const c_int,
-        n: *const c_int,
-        l: *const c_int,
-        mb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        l: *const lapack_int,
+        mb: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         T: *mut __BindgenComplex<f64>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctplqt2_64_")]
     pub fn ctplqt2_(
-        m: *const c_int,
-        n: *const c_int,
-        l: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        l: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         T: *mut __BindgenComplex<f32>,
-        ldt: *const c_int,
-        info: *mut c_int,
+        ldt: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtplqt2_64_")]
     pub fn dtplqt2_(
-        m: *const c_int,
-        n: *const c_int,
-        l: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        l: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         T: *mut f64,
-        ldt: *const c_int,
-        info: *mut c_int,
+        ldt: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stplqt2_64_")]
     pub fn stplqt2_(
-        m: *const c_int,
-        n: *const c_int,
-        l: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        l: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         T: *mut f32,
-        ldt: *const c_int,
-        info: *mut c_int,
+        ldt: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztplqt2_64_")]
     pub fn ztplqt2_(
-        m: *const c_int,
-        n: *const c_int,
-        l: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        l: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         T: *mut __BindgenComplex<f64>,
-        ldt: *const c_int,
-        info: *mut c_int,
+        ldt: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctpmlqt_64_")]
     pub fn ctpmlqt_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        l: *const c_int,
-        mb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        l: *const lapack_int,
+        mb: *const lapack_int,
         V: *const __BindgenComplex<f32>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         T: *const __BindgenComplex<f32>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtpmlqt_64_")]
     pub fn dtpmlqt_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        l: *const c_int,
-        mb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        l: *const lapack_int,
+        mb: *const lapack_int,
         V: *const f64,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         T: *const f64,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stpmlqt_64_")]
     pub fn stpmlqt_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        l: *const c_int,
-        mb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        l: *const lapack_int,
+        mb: *const lapack_int,
         V: *const f32,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         T: *const f32,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztpmlqt_64_")]
     pub fn ztpmlqt_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        l: *const c_int,
-        mb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        l: *const lapack_int,
+        mb: *const lapack_int,
         V: *const __BindgenComplex<f64>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         T: *const __BindgenComplex<f64>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctpmqrt_64_")]
     pub fn ctpmqrt_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        l: *const c_int,
-        nb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        l: *const lapack_int,
+        nb: *const lapack_int,
         V: *const __BindgenComplex<f32>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         T: *const __BindgenComplex<f32>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtpmqrt_64_")]
     pub fn dtpmqrt_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        l: *const c_int,
-        nb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        l: *const lapack_int,
+        nb: *const lapack_int,
         V: *const f64,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         T: *const f64,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stpmqrt_64_")]
     pub fn stpmqrt_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        l: *const c_int,
-        nb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        l: *const lapack_int,
+        nb: *const lapack_int,
         V: *const f32,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         T: *const f32,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztpmqrt_64_")]
     pub fn ztpmqrt_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        l: *const c_int,
-        nb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        l: *const lapack_int,
+        nb: *const lapack_int,
         V: *const __BindgenComplex<f64>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         T: *const __BindgenComplex<f64>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctpqrt_64_")]
     pub fn ctpqrt_(
-        m: *const c_int,
-        n: *const c_int,
-        l: *const c_int,
-        nb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        l: *const lapack_int,
+        nb: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         T: *mut __BindgenComplex<f32>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtpqrt_64_")]
     pub fn dtpqrt_(
-        m: *const c_int,
-        n: *const c_int,
-        l: *const c_int,
-        nb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        l: *const lapack_int,
+        nb: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         T: *mut f64,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stpqrt_64_")]
     pub fn stpqrt_(
-        m: *const c_int,
-        n: *const c_int,
-        l: *const c_int,
-        nb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        l: *const lapack_int,
+        nb: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         T: *mut f32,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztpqrt_64_")]
     pub fn ztpqrt_(
-        m: *const c_int,
-        n: *const c_int,
-        l: *const c_int,
-        nb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        l: *const lapack_int,
+        nb: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         T: *mut __BindgenComplex<f64>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctpqrt2_64_")]
     pub fn ctpqrt2_(
-        m: *const c_int,
-        n: *const c_int,
-        l: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        l: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         T: *mut __BindgenComplex<f32>,
-        ldt: *const c_int,
-        info: *mut c_int,
+        ldt: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtpqrt2_64_")]
     pub fn dtpqrt2_(
-        m: *const c_int,
-        n: *const c_int,
-        l: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        l: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         T: *mut f64,
-        ldt: *const c_int,
-        info: *mut c_int,
+        ldt: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stpqrt2_64_")]
     pub fn stpqrt2_(
-        m: *const c_int,
-        n: *const c_int,
-        l: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        l: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         T: *mut f32,
-        ldt: *const c_int,
-        info: *mut c_int,
+        ldt: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztpqrt2_64_")]
     pub fn ztpqrt2_(
-        m: *const c_int,
-        n: *const c_int,
-        l: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        l: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         T: *mut __BindgenComplex<f64>,
-        ldt: *const c_int,
-        info: *mut c_int,
+        ldt: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctprfb_64_")]
     pub fn ctprfb_(
         side: *const c_char,
         trans: *const c_char,
         direct: *const c_char,
         storev: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        l: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        l: *const lapack_int,
         V: *const __BindgenComplex<f32>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         T: *const __BindgenComplex<f32>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        ldwork: *const c_int,
+        ldwork: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtprfb_64_")]
     pub fn dtprfb_(
         side: *const c_char,
         trans: *const c_char,
         direct: *const c_char,
         storev: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        l: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        l: *const lapack_int,
         V: *const f64,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         T: *const f64,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f64,
-        ldwork: *const c_int,
+        ldwork: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stprfb_64_")]
     pub fn stprfb_(
         side: *const c_char,
         trans: *const c_char,
         direct: *const c_char,
         storev: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        l: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        l: *const lapack_int,
         V: *const f32,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         T: *const f32,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut f32,
-        ldwork: *const c_int,
+        ldwork: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztprfb_64_")]
     pub fn ztprfb_(
         side: *const c_char,
         trans: *const c_char,
         direct: *const c_char,
         storev: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        l: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        l: *const lapack_int,
         V: *const __BindgenComplex<f64>,
-        ldv: *const c_int,
+        ldv: *const lapack_int,
         T: *const __BindgenComplex<f64>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        ldwork: *const c_int,
+        ldwork: *const lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctprfs_64_")]
     pub fn ctprfs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const __BindgenComplex<f32>,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *const __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtprfs_64_")]
     pub fn dtprfs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const f64,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *const f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stprfs_64_")]
     pub fn stprfs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const f32,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *const f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztprfs_64_")]
     pub fn ztprfs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const __BindgenComplex<f64>,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *const __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctptri_64_")]
     pub fn ctptri_(
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtptri_64_")]
     pub fn dtptri_(
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stptri_64_")]
     pub fn stptri_(
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztptri_64_")]
     pub fn ztptri_(
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctptrs_64_")]
     pub fn ctptrs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const __BindgenComplex<f32>,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtptrs_64_")]
     pub fn dtptrs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const f64,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stptrs_64_")]
     pub fn stptrs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const f32,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztptrs_64_")]
     pub fn ztptrs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         AP: *const __BindgenComplex<f64>,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctpttf_64_")]
     pub fn ctpttf_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f32>,
         ARF: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtpttf_64_")]
     pub fn dtpttf_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const f64,
         ARF: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stpttf_64_")]
     pub fn stpttf_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const f32,
         ARF: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztpttf_64_")]
     pub fn ztpttf_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f64>,
         ARF: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctpttr_64_")]
     pub fn ctpttr_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f32>,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtpttr_64_")]
     pub fn dtpttr_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const f64,
         A: *mut f64,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stpttr_64_")]
     pub fn stpttr_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const f32,
         A: *mut f32,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztpttr_64_")]
     pub fn ztpttr_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f64>,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctrcon_64_")]
     pub fn ctrcon_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         rcond: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtrcon_64_")]
     pub fn dtrcon_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         rcond: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "strcon_64_")]
     pub fn strcon_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         rcond: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztrcon_64_")]
     pub fn ztrcon_(
         norm: *const c_char,
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         rcond: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctrevc_64_")]
     pub fn ctrevc_(
         side: *const c_char,
         howmny: *const c_char,
-        select: *const c_int,
-        n: *const c_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         T: *mut __BindgenComplex<f32>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         VL: *mut __BindgenComplex<f32>,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut __BindgenComplex<f32>,
-        ldvr: *const c_int,
-        mm: *const c_int,
-        m: *mut c_int,
+        ldvr: *const lapack_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtrevc_64_")]
     pub fn dtrevc_(
         side: *const c_char,
         howmny: *const c_char,
-        select: *mut c_int,
-        n: *const c_int,
+        select: *mut lapack_int,
+        n: *const lapack_int,
         T: *const f64,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         VL: *mut f64,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut f64,
-        ldvr: *const c_int,
-        mm: *const c_int,
-        m: *mut c_int,
+        ldvr: *const lapack_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "strevc_64_")]
     pub fn strevc_(
         side: *const c_char,
         howmny: *const c_char,
-        select: *mut c_int,
-        n: *const c_int,
+        select: *mut lapack_int,
+        n: *const lapack_int,
         T: *const f32,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         VL: *mut f32,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut f32,
-        ldvr: *const c_int,
-        mm: *const c_int,
-        m: *mut c_int,
+        ldvr: *const lapack_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztrevc_64_")]
     pub fn ztrevc_(
         side: *const c_char,
         howmny: *const c_char,
-        select: *const c_int,
-        n: *const c_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         T: *mut __BindgenComplex<f64>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         VL: *mut __BindgenComplex<f64>,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut __BindgenComplex<f64>,
-        ldvr: *const c_int,
-        mm: *const c_int,
-        m: *mut c_int,
+        ldvr: *const lapack_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctrevc3_64_")]
     pub fn ctrevc3_(
         side: *const c_char,
         howmny: *const c_char,
-        select: *const c_int,
-        n: *const c_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         T: *mut __BindgenComplex<f32>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         VL: *mut __BindgenComplex<f32>,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut __BindgenComplex<f32>,
-        ldvr: *const c_int,
-        mm: *const c_int,
-        m: *mut c_int,
+        ldvr: *const lapack_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        lrwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtrevc3_64_")]
     pub fn dtrevc3_(
         side: *const c_char,
         howmny: *const c_char,
-        select: *mut c_int,
-        n: *const c_int,
+        select: *mut lapack_int,
+        n: *const lapack_int,
         T: *const f64,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         VL: *mut f64,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut f64,
-        ldvr: *const c_int,
-        mm: *const c_int,
-        m: *mut c_int,
+        ldvr: *const lapack_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "strevc3_64_")]
     pub fn strevc3_(
         side: *const c_char,
         howmny: *const c_char,
-        select: *mut c_int,
-        n: *const c_int,
+        select: *mut lapack_int,
+        n: *const lapack_int,
         T: *const f32,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         VL: *mut f32,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut f32,
-        ldvr: *const c_int,
-        mm: *const c_int,
-        m: *mut c_int,
+        ldvr: *const lapack_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztrevc3_64_")]
     pub fn ztrevc3_(
         side: *const c_char,
         howmny: *const c_char,
-        select: *const c_int,
-        n: *const c_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         T: *mut __BindgenComplex<f64>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         VL: *mut __BindgenComplex<f64>,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *mut __BindgenComplex<f64>,
-        ldvr: *const c_int,
-        mm: *const c_int,
-        m: *mut c_int,
+        ldvr: *const lapack_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        lrwork: *const c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctrexc_64_")]
     pub fn ctrexc_(
         compq: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         T: *mut __BindgenComplex<f32>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         Q: *mut __BindgenComplex<f32>,
-        ldq: *const c_int,
-        ifst: *const c_int,
-        ilst: *const c_int,
-        info: *mut c_int,
+        ldq: *const lapack_int,
+        ifst: *const lapack_int,
+        ilst: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtrexc_64_")]
     pub fn dtrexc_(
         compq: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         T: *mut f64,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         Q: *mut f64,
-        ldq: *const c_int,
-        ifst: *mut c_int,
-        ilst: *mut c_int,
+        ldq: *const lapack_int,
+        ifst: *mut lapack_int,
+        ilst: *mut lapack_int,
         work: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "strexc_64_")]
     pub fn strexc_(
         compq: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         T: *mut f32,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         Q: *mut f32,
-        ldq: *const c_int,
-        ifst: *mut c_int,
-        ilst: *mut c_int,
+        ldq: *const lapack_int,
+        ifst: *mut lapack_int,
+        ilst: *mut lapack_int,
         work: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztrexc_64_")]
     pub fn ztrexc_(
         compq: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         T: *mut __BindgenComplex<f64>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         Q: *mut __BindgenComplex<f64>,
-        ldq: *const c_int,
-        ifst: *const c_int,
-        ilst: *const c_int,
-        info: *mut c_int,
+        ldq: *const lapack_int,
+        ifst: *const lapack_int,
+        ilst: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctrrfs_64_")]
     pub fn ctrrfs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *const __BindgenComplex<f32>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut __BindgenComplex<f32>,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtrrfs_64_")]
     pub fn dtrrfs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *const f64,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut f64,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "strrfs_64_")]
     pub fn strrfs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *const f32,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f32,
         berr: *mut f32,
         work: *mut f32,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztrrfs_64_")]
     pub fn ztrrfs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         X: *const __BindgenComplex<f64>,
-        ldx: *const c_int,
+        ldx: *const lapack_int,
         ferr: *mut f64,
         berr: *mut f64,
         work: *mut __BindgenComplex<f64>,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctrsen_64_")]
     pub fn ctrsen_(
         job: *const c_char,
         compq: *const c_char,
-        select: *const c_int,
-        n: *const c_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         T: *mut __BindgenComplex<f32>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         Q: *mut __BindgenComplex<f32>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         W: *mut __BindgenComplex<f32>,
-        m: *mut c_int,
+        m: *mut lapack_int,
         s: *mut f32,
         sep: *mut f32,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtrsen_64_")]
     pub fn dtrsen_(
         job: *const c_char,
         compq: *const c_char,
-        select: *const c_int,
-        n: *const c_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         T: *mut f64,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         Q: *mut f64,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         WR: *mut f64,
         WI: *mut f64,
-        m: *mut c_int,
+        m: *mut lapack_int,
         s: *mut f64,
         sep: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "strsen_64_")]
     pub fn strsen_(
         job: *const c_char,
         compq: *const c_char,
-        select: *const c_int,
-        n: *const c_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         T: *mut f32,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         Q: *mut f32,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         WR: *mut f32,
         WI: *mut f32,
-        m: *mut c_int,
+        m: *mut lapack_int,
         s: *mut f32,
         sep: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        iwork: *mut c_int,
-        liwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        liwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztrsen_64_")]
     pub fn ztrsen_(
         job: *const c_char,
         compq: *const c_char,
-        select: *const c_int,
-        n: *const c_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         T: *mut __BindgenComplex<f64>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         Q: *mut __BindgenComplex<f64>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         W: *mut __BindgenComplex<f64>,
-        m: *mut c_int,
+        m: *mut lapack_int,
         s: *mut f64,
         sep: *mut f64,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctrsna_64_")]
     pub fn ctrsna_(
         job: *const c_char,
         howmny: *const c_char,
-        select: *const c_int,
-        n: *const c_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         T: *const __BindgenComplex<f32>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         VL: *const __BindgenComplex<f32>,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *const __BindgenComplex<f32>,
-        ldvr: *const c_int,
+        ldvr: *const lapack_int,
         S: *mut f32,
         SEP: *mut f32,
-        mm: *const c_int,
-        m: *mut c_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut __BindgenComplex<f32>,
-        ldwork: *const c_int,
+        ldwork: *const lapack_int,
         rwork: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtrsna_64_")]
     pub fn dtrsna_(
         job: *const c_char,
         howmny: *const c_char,
-        select: *const c_int,
-        n: *const c_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         T: *const f64,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         VL: *const f64,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *const f64,
-        ldvr: *const c_int,
+        ldvr: *const lapack_int,
         S: *mut f64,
         SEP: *mut f64,
-        mm: *const c_int,
-        m: *mut c_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut f64,
-        ldwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        ldwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "strsna_64_")]
     pub fn strsna_(
         job: *const c_char,
         howmny: *const c_char,
-        select: *const c_int,
-        n: *const c_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         T: *const f32,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         VL: *const f32,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *const f32,
-        ldvr: *const c_int,
+        ldvr: *const lapack_int,
         S: *mut f32,
         SEP: *mut f32,
-        mm: *const c_int,
-        m: *mut c_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut f32,
-        ldwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        ldwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztrsna_64_")]
     pub fn ztrsna_(
         job: *const c_char,
         howmny: *const c_char,
-        select: *const c_int,
-        n: *const c_int,
+        select: *const lapack_int,
+        n: *const lapack_int,
         T: *const __BindgenComplex<f64>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         VL: *const __BindgenComplex<f64>,
-        ldvl: *const c_int,
+        ldvl: *const lapack_int,
         VR: *const __BindgenComplex<f64>,
-        ldvr: *const c_int,
+        ldvr: *const lapack_int,
         S: *mut f64,
         SEP: *mut f64,
-        mm: *const c_int,
-        m: *mut c_int,
+        mm: *const lapack_int,
+        m: *mut lapack_int,
         work: *mut __BindgenComplex<f64>,
-        ldwork: *const c_int,
+        ldwork: *const lapack_int,
         rwork: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctrsyl_64_")]
     pub fn ctrsyl_(
         trana: *const c_char,
         tranb: *const c_char,
-        isgn: *const c_int,
-        m: *const c_int,
-        n: *const c_int,
+        isgn: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const __BindgenComplex<f32>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         C: *mut __BindgenComplex<f32>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         scale: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtrsyl_64_")]
     pub fn dtrsyl_(
         trana: *const c_char,
         tranb: *const c_char,
-        isgn: *const c_int,
-        m: *const c_int,
-        n: *const c_int,
+        isgn: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const f64,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         C: *mut f64,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         scale: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "strsyl_64_")]
     pub fn strsyl_(
         trana: *const c_char,
         tranb: *const c_char,
-        isgn: *const c_int,
-        m: *const c_int,
-        n: *const c_int,
+        isgn: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const f32,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         C: *mut f32,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         scale: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztrsyl_64_")]
     pub fn ztrsyl_(
         trana: *const c_char,
         tranb: *const c_char,
-        isgn: *const c_int,
-        m: *const c_int,
-        n: *const c_int,
+        isgn: *const lapack_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *const __BindgenComplex<f64>,
-        ldb: *const c_int,
+        ldb: *const lapack_int,
         C: *mut __BindgenComplex<f64>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         scale: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctrtri_64_")]
     pub fn ctrtri_(
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtrtri_64_")]
     pub fn dtrtri_(
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "strtri_64_")]
     pub fn strtri_(
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztrtri_64_")]
     pub fn ztrtri_(
         uplo: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
-        info: *mut c_int,
+        lda: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctrtrs_64_")]
     pub fn ctrtrs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f32>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtrtrs_64_")]
     pub fn dtrtrs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f64,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "strtrs_64_")]
     pub fn strtrs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut f32,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztrtrs_64_")]
     pub fn ztrtrs_(
         uplo: *const c_char,
         trans: *const c_char,
         diag: *const c_char,
-        n: *const c_int,
-        nrhs: *const c_int,
+        n: *const lapack_int,
+        nrhs: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         B: *mut __BindgenComplex<f64>,
-        ldb: *const c_int,
-        info: *mut c_int,
+        ldb: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctrttf_64_")]
     pub fn ctrttf_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         ARF: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtrttf_64_")]
     pub fn dtrttf_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         ARF: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "strttf_64_")]
     pub fn strttf_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         ARF: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztrttf_64_")]
     pub fn ztrttf_(
         transr: *const c_char,
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         ARF: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctrttp_64_")]
     pub fn ctrttp_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AP: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtrttp_64_")]
     pub fn dtrttp_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AP: *mut f64,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "strttp_64_")]
     pub fn strttp_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AP: *mut f32,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztrttp_64_")]
     pub fn ztrttp_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         AP: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ctzrzf_64_")]
     pub fn ctzrzf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dtzrzf_64_")]
     pub fn dtzrzf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f64,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut f64,
         work: *mut f64,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "stzrzf_64_")]
     pub fn stzrzf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut f32,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut f32,
         work: *mut f32,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "ztzrzf_64_")]
     pub fn ztzrzf_(
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *mut __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cunbdb_64_")]
     pub fn cunbdb_(
         trans: *const c_char,
         signs: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        q: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        q: *const lapack_int,
         X11: *mut __BindgenComplex<f32>,
-        ldx11: *const c_int,
+        ldx11: *const lapack_int,
         X12: *mut __BindgenComplex<f32>,
-        ldx12: *const c_int,
+        ldx12: *const lapack_int,
         X21: *mut __BindgenComplex<f32>,
-        ldx21: *const c_int,
+        ldx21: *const lapack_int,
         X22: *mut __BindgenComplex<f32>,
-        ldx22: *const c_int,
+        ldx22: *const lapack_int,
         theta: *mut f32,
         phi: *mut f32,
         TAUP1: *mut __BindgenComplex<f32>,
@@ -20365,25 +21677,26 @@ extern "C" {
         TAUQ1: *mut __BindgenComplex<f32>,
         TAUQ2: *mut __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zunbdb_64_")]
     pub fn zunbdb_(
         trans: *const c_char,
         signs: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        q: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        q: *const lapack_int,
         X11: *mut __BindgenComplex<f64>,
-        ldx11: *const c_int,
+        ldx11: *const lapack_int,
         X12: *mut __BindgenComplex<f64>,
-        ldx12: *const c_int,
+        ldx12: *const lapack_int,
         X21: *mut __BindgenComplex<f64>,
-        ldx21: *const c_int,
+        ldx21: *const lapack_int,
         X22: *mut __BindgenComplex<f64>,
-        ldx22: *const c_int,
+        ldx22: *const lapack_int,
         theta: *mut f64,
         phi: *mut f64,
         TAUP1: *mut __BindgenComplex<f64>,
@@ -20391,11 +21704,12 @@ extern "C" {
         TAUQ1: *mut __BindgenComplex<f64>,
         TAUQ2: *mut __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cuncsd_64_")]
     pub fn cuncsd_(
         jobu1: *const c_char,
         jobu2: *const c_char,
@@ -20403,35 +21717,36 @@ extern "C" {
         jobv2t: *const c_char,
         trans: *const c_char,
         signs: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        q: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        q: *const lapack_int,
         X11: *mut __BindgenComplex<f32>,
-        ldx11: *const c_int,
+        ldx11: *const lapack_int,
         X12: *mut __BindgenComplex<f32>,
-        ldx12: *const c_int,
+        ldx12: *const lapack_int,
         X21: *mut __BindgenComplex<f32>,
-        ldx21: *const c_int,
+        ldx21: *const lapack_int,
         X22: *mut __BindgenComplex<f32>,
-        ldx22: *const c_int,
+        ldx22: *const lapack_int,
         theta: *mut f32,
         U1: *mut __BindgenComplex<f32>,
-        ldu1: *const c_int,
+        ldu1: *const lapack_int,
         U2: *mut __BindgenComplex<f32>,
-        ldu2: *const c_int,
+        ldu2: *const lapack_int,
         V1T: *mut __BindgenComplex<f32>,
-        ldv1t: *const c_int,
+        ldv1t: *const lapack_int,
         V2T: *mut __BindgenComplex<f32>,
-        ldv2t: *const c_int,
+        ldv2t: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zuncsd_64_")]
     pub fn zuncsd_(
         jobu1: *const c_char,
         jobu2: *const c_char,
@@ -20439,629 +21754,667 @@ extern "C" {
         jobv2t: *const c_char,
         trans: *const c_char,
         signs: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        q: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        q: *const lapack_int,
         X11: *mut __BindgenComplex<f64>,
-        ldx11: *const c_int,
+        ldx11: *const lapack_int,
         X12: *mut __BindgenComplex<f64>,
-        ldx12: *const c_int,
+        ldx12: *const lapack_int,
         X21: *mut __BindgenComplex<f64>,
-        ldx21: *const c_int,
+        ldx21: *const lapack_int,
         X22: *mut __BindgenComplex<f64>,
-        ldx22: *const c_int,
+        ldx22: *const lapack_int,
         theta: *mut f64,
         U1: *mut __BindgenComplex<f64>,
-        ldu1: *const c_int,
+        ldu1: *const lapack_int,
         U2: *mut __BindgenComplex<f64>,
-        ldu2: *const c_int,
+        ldu2: *const lapack_int,
         V1T: *mut __BindgenComplex<f64>,
-        ldv1t: *const c_int,
+        ldv1t: *const lapack_int,
         V2T: *mut __BindgenComplex<f64>,
-        ldv2t: *const c_int,
+        ldv2t: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cuncsd2by1_64_")]
     pub fn cuncsd2by1_(
         jobu1: *const c_char,
         jobu2: *const c_char,
         jobv1t: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        q: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        q: *const lapack_int,
         X11: *mut __BindgenComplex<f32>,
-        ldx11: *const c_int,
+        ldx11: *const lapack_int,
         X21: *mut __BindgenComplex<f32>,
-        ldx21: *const c_int,
+        ldx21: *const lapack_int,
         theta: *mut f32,
         U1: *mut __BindgenComplex<f32>,
-        ldu1: *const c_int,
+        ldu1: *const lapack_int,
         U2: *mut __BindgenComplex<f32>,
-        ldu2: *const c_int,
+        ldu2: *const lapack_int,
         V1T: *mut __BindgenComplex<f32>,
-        ldv1t: *const c_int,
+        ldv1t: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f32,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zuncsd2by1_64_")]
     pub fn zuncsd2by1_(
         jobu1: *const c_char,
         jobu2: *const c_char,
         jobv1t: *const c_char,
-        m: *const c_int,
-        p: *const c_int,
-        q: *const c_int,
+        m: *const lapack_int,
+        p: *const lapack_int,
+        q: *const lapack_int,
         X11: *mut __BindgenComplex<f64>,
-        ldx11: *const c_int,
+        ldx11: *const lapack_int,
         X21: *mut __BindgenComplex<f64>,
-        ldx21: *const c_int,
+        ldx21: *const lapack_int,
         theta: *mut f64,
         U1: *mut __BindgenComplex<f64>,
-        ldu1: *const c_int,
+        ldu1: *const lapack_int,
         U2: *mut __BindgenComplex<f64>,
-        ldu2: *const c_int,
+        ldu2: *const lapack_int,
         V1T: *mut __BindgenComplex<f64>,
-        ldv1t: *const c_int,
+        ldv1t: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
+        lwork: *const lapack_int,
         rwork: *mut f64,
-        lrwork: *const c_int,
-        iwork: *mut c_int,
-        info: *mut c_int,
+        lrwork: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cungbr_64_")]
     pub fn cungbr_(
         vect: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zungbr_64_")]
     pub fn zungbr_(
         vect: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cunghr_64_")]
     pub fn cunghr_(
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zunghr_64_")]
     pub fn zunghr_(
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cunglq_64_")]
     pub fn cunglq_(
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zunglq_64_")]
     pub fn zunglq_(
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cungql_64_")]
     pub fn cungql_(
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zungql_64_")]
     pub fn zungql_(
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cungqr_64_")]
     pub fn cungqr_(
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zungqr_64_")]
     pub fn zungqr_(
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cungrq_64_")]
     pub fn cungrq_(
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zungrq_64_")]
     pub fn zungrq_(
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cungtr_64_")]
     pub fn cungtr_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f32>,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zungtr_64_")]
     pub fn zungtr_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f64>,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cungtsqr_row_64_")]
     pub fn cungtsqr_row_(
-        m: *const c_int,
-        n: *const c_int,
-        mb: *const c_int,
-        nb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        mb: *const lapack_int,
+        nb: *const lapack_int,
         A: *mut __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *const __BindgenComplex<f32>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zungtsqr_row_64_")]
     pub fn zungtsqr_row_(
-        m: *const c_int,
-        n: *const c_int,
-        mb: *const c_int,
-        nb: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        mb: *const lapack_int,
+        nb: *const lapack_int,
         A: *mut __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         T: *const __BindgenComplex<f64>,
-        ldt: *const c_int,
+        ldt: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cunmbr_64_")]
     pub fn cunmbr_(
         vect: *const c_char,
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f32>,
         C: *mut __BindgenComplex<f32>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zunmbr_64_")]
     pub fn zunmbr_(
         vect: *const c_char,
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f64>,
         C: *mut __BindgenComplex<f64>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cunmhr_64_")]
     pub fn cunmhr_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f32>,
         C: *mut __BindgenComplex<f32>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zunmhr_64_")]
     pub fn zunmhr_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        ilo: *const c_int,
-        ihi: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        ilo: *const lapack_int,
+        ihi: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f64>,
         C: *mut __BindgenComplex<f64>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cunmlq_64_")]
     pub fn cunmlq_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f32>,
         C: *mut __BindgenComplex<f32>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zunmlq_64_")]
     pub fn zunmlq_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f64>,
         C: *mut __BindgenComplex<f64>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cunmql_64_")]
     pub fn cunmql_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f32>,
         C: *mut __BindgenComplex<f32>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zunmql_64_")]
     pub fn zunmql_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f64>,
         C: *mut __BindgenComplex<f64>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cunmqr_64_")]
     pub fn cunmqr_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f32>,
         C: *mut __BindgenComplex<f32>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zunmqr_64_")]
     pub fn zunmqr_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f64>,
         C: *mut __BindgenComplex<f64>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cunmrq_64_")]
     pub fn cunmrq_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f32>,
         C: *mut __BindgenComplex<f32>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zunmrq_64_")]
     pub fn zunmrq_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f64>,
         C: *mut __BindgenComplex<f64>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cunmrz_64_")]
     pub fn cunmrz_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        l: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        l: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f32>,
         C: *mut __BindgenComplex<f32>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zunmrz_64_")]
     pub fn zunmrz_(
         side: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
-        k: *const c_int,
-        l: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        k: *const lapack_int,
+        l: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f64>,
         C: *mut __BindgenComplex<f64>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cunmtr_64_")]
     pub fn cunmtr_(
         side: *const c_char,
         uplo: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f32>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f32>,
         C: *mut __BindgenComplex<f32>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zunmtr_64_")]
     pub fn zunmtr_(
         side: *const c_char,
         uplo: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         A: *const __BindgenComplex<f64>,
-        lda: *const c_int,
+        lda: *const lapack_int,
         tau: *const __BindgenComplex<f64>,
         C: *mut __BindgenComplex<f64>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        lwork: *const c_int,
-        info: *mut c_int,
+        lwork: *const lapack_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cupgtr_64_")]
     pub fn cupgtr_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f32>,
         tau: *const __BindgenComplex<f32>,
         Q: *mut __BindgenComplex<f32>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zupgtr_64_")]
     pub fn zupgtr_(
         uplo: *const c_char,
-        n: *const c_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f64>,
         tau: *const __BindgenComplex<f64>,
         Q: *mut __BindgenComplex<f64>,
-        ldq: *const c_int,
+        ldq: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "cupmtr_64_")]
     pub fn cupmtr_(
         side: *const c_char,
         uplo: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f32>,
         tau: *const __BindgenComplex<f32>,
         C: *mut __BindgenComplex<f32>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f32>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
 extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zupmtr_64_")]
     pub fn zupmtr_(
         side: *const c_char,
         uplo: *const c_char,
         trans: *const c_char,
-        m: *const c_int,
-        n: *const c_int,
+        m: *const lapack_int,
+        n: *const lapack_int,
         AP: *const __BindgenComplex<f64>,
         tau: *const __BindgenComplex<f64>,
         C: *mut __BindgenComplex<f64>,
-        ldc: *const c_int,
+        ldc: *const lapack_int,
         work: *mut __BindgenComplex<f64>,
-        info: *mut c_int,
+        info: *mut lapack_int,
     );
 }
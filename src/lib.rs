@@ -10,7 +10,7 @@
 
 extern crate libc;
 
-use libc::{c_char, c_int, size_t};
+use libc::{c_char, size_t};
 
 /// A complex number with 64-bit parts.
 pub type c_double_complex = __BindgenComplex<f64>;
@@ -20,7 +20,31 @@ pub type c_float_complex = __BindgenComplex<f32>;
 
 pub type lapack_complex_double = c_double_complex;
 pub type lapack_complex_float = c_float_complex;
+#[cfg(not(feature = "ilp64"))]
 pub type lapack_int = i32;
+#[cfg(feature = "ilp64")]
+pub type lapack_int = i64;
 pub type lapack_logical = lapack_int;
 
 include!("lapack.rs");
+include!("extra.rs");
+
+#[cfg(feature = "deprecated-routines")]
+include!("deprecated.rs");
+
+#[cfg(feature = "timing")]
+include!("timing.rs");
+
+#[cfg(feature = "tmg")]
+#[link(name = "tmglib")]
+extern "C" {}
+
+#[cfg(feature = "tmg")]
+include!("tmg.rs");
+
+/// Return the version of the linked LAPACK as `(major, minor, patch)`.
+pub fn lapack_version() -> (lapack_int, lapack_int, lapack_int) {
+    let (mut major, mut minor, mut patch) = (0, 0, 0);
+    unsafe { ilaver_(&mut major, &mut minor, &mut patch) };
+    (major, minor, patch)
+}
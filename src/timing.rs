@@ -0,0 +1,11 @@
+// Timers of the LAPACK installation, which not all builds export. They are
+// available with the `timing` feature.
+
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "second_64_")]
+    pub fn second_() -> lapack_float_return;
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dsecnd_64_")]
+    pub fn dsecnd_() -> f64;
+}
@@ -0,0 +1,293 @@
+// Routines of the test matrix generator library (TMG) that are not declared in
+// `lapack.h`. They are available with the `tmg` feature.
+
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "claror_64_")]
+    pub fn claror_(
+        side: *const c_char,
+        init: *const c_char,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        A: *mut __BindgenComplex<f32>,
+        lda: *const lapack_int,
+        iseed: *mut lapack_int,
+        X: *mut __BindgenComplex<f32>,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlaror_64_")]
+    pub fn dlaror_(
+        side: *const c_char,
+        init: *const c_char,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        A: *mut f64,
+        lda: *const lapack_int,
+        iseed: *mut lapack_int,
+        X: *mut f64,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slaror_64_")]
+    pub fn slaror_(
+        side: *const c_char,
+        init: *const c_char,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        A: *mut f32,
+        lda: *const lapack_int,
+        iseed: *mut lapack_int,
+        X: *mut f32,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlaror_64_")]
+    pub fn zlaror_(
+        side: *const c_char,
+        init: *const c_char,
+        m: *const lapack_int,
+        n: *const lapack_int,
+        A: *mut __BindgenComplex<f64>,
+        lda: *const lapack_int,
+        iseed: *mut lapack_int,
+        X: *mut __BindgenComplex<f64>,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clatme_64_")]
+    pub fn clatme_(
+        n: *const lapack_int,
+        dist: *const c_char,
+        iseed: *mut lapack_int,
+        D: *mut __BindgenComplex<f32>,
+        mode: *const lapack_int,
+        cond: *const f32,
+        dmax: *const __BindgenComplex<f32>,
+        rsign: *const c_char,
+        upper: *const c_char,
+        sim: *const c_char,
+        DS: *mut f32,
+        modes: *const lapack_int,
+        conds: *const f32,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        anorm: *const f32,
+        A: *mut __BindgenComplex<f32>,
+        lda: *const lapack_int,
+        work: *mut __BindgenComplex<f32>,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlatme_64_")]
+    pub fn dlatme_(
+        n: *const lapack_int,
+        dist: *const c_char,
+        iseed: *mut lapack_int,
+        D: *mut f64,
+        mode: *const lapack_int,
+        cond: *const f64,
+        dmax: *const f64,
+        EI: *const c_char,
+        rsign: *const c_char,
+        upper: *const c_char,
+        sim: *const c_char,
+        DS: *mut f64,
+        modes: *const lapack_int,
+        conds: *const f64,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        anorm: *const f64,
+        A: *mut f64,
+        lda: *const lapack_int,
+        work: *mut f64,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slatme_64_")]
+    pub fn slatme_(
+        n: *const lapack_int,
+        dist: *const c_char,
+        iseed: *mut lapack_int,
+        D: *mut f32,
+        mode: *const lapack_int,
+        cond: *const f32,
+        dmax: *const f32,
+        EI: *const c_char,
+        rsign: *const c_char,
+        upper: *const c_char,
+        sim: *const c_char,
+        DS: *mut f32,
+        modes: *const lapack_int,
+        conds: *const f32,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        anorm: *const f32,
+        A: *mut f32,
+        lda: *const lapack_int,
+        work: *mut f32,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlatme_64_")]
+    pub fn zlatme_(
+        n: *const lapack_int,
+        dist: *const c_char,
+        iseed: *mut lapack_int,
+        D: *mut __BindgenComplex<f64>,
+        mode: *const lapack_int,
+        cond: *const f64,
+        dmax: *const __BindgenComplex<f64>,
+        rsign: *const c_char,
+        upper: *const c_char,
+        sim: *const c_char,
+        DS: *mut f64,
+        modes: *const lapack_int,
+        conds: *const f64,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        anorm: *const f64,
+        A: *mut __BindgenComplex<f64>,
+        lda: *const lapack_int,
+        work: *mut __BindgenComplex<f64>,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "clatmr_64_")]
+    pub fn clatmr_(
+        m: *const lapack_int,
+        n: *const lapack_int,
+        dist: *const c_char,
+        iseed: *mut lapack_int,
+        sym: *const c_char,
+        D: *mut __BindgenComplex<f32>,
+        mode: *const lapack_int,
+        cond: *const f32,
+        dmax: *const __BindgenComplex<f32>,
+        rsign: *const c_char,
+        grade: *const c_char,
+        DL: *mut __BindgenComplex<f32>,
+        model: *const lapack_int,
+        condl: *const f32,
+        DR: *mut __BindgenComplex<f32>,
+        moder: *const lapack_int,
+        condr: *const f32,
+        pivtng: *const c_char,
+        ipivot: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        sparse: *const f32,
+        anorm: *const f32,
+        pack: *const c_char,
+        A: *mut __BindgenComplex<f32>,
+        lda: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "dlatmr_64_")]
+    pub fn dlatmr_(
+        m: *const lapack_int,
+        n: *const lapack_int,
+        dist: *const c_char,
+        iseed: *mut lapack_int,
+        sym: *const c_char,
+        D: *mut f64,
+        mode: *const lapack_int,
+        cond: *const f64,
+        dmax: *const f64,
+        rsign: *const c_char,
+        grade: *const c_char,
+        DL: *mut f64,
+        model: *const lapack_int,
+        condl: *const f64,
+        DR: *mut f64,
+        moder: *const lapack_int,
+        condr: *const f64,
+        pivtng: *const c_char,
+        ipivot: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        sparse: *const f64,
+        anorm: *const f64,
+        pack: *const c_char,
+        A: *mut f64,
+        lda: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "slatmr_64_")]
+    pub fn slatmr_(
+        m: *const lapack_int,
+        n: *const lapack_int,
+        dist: *const c_char,
+        iseed: *mut lapack_int,
+        sym: *const c_char,
+        D: *mut f32,
+        mode: *const lapack_int,
+        cond: *const f32,
+        dmax: *const f32,
+        rsign: *const c_char,
+        grade: *const c_char,
+        DL: *mut f32,
+        model: *const lapack_int,
+        condl: *const f32,
+        DR: *mut f32,
+        moder: *const lapack_int,
+        condr: *const f32,
+        pivtng: *const c_char,
+        ipivot: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        sparse: *const f32,
+        anorm: *const f32,
+        pack: *const c_char,
+        A: *mut f32,
+        lda: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
+    );
+}
+extern "C" {
+    #[cfg_attr(feature = "ilp64-suffix", link_name = "zlatmr_64_")]
+    pub fn zlatmr_(
+        m: *const lapack_int,
+        n: *const lapack_int,
+        dist: *const c_char,
+        iseed: *mut lapack_int,
+        sym: *const c_char,
+        D: *mut __BindgenComplex<f64>,
+        mode: *const lapack_int,
+        cond: *const f64,
+        dmax: *const __BindgenComplex<f64>,
+        rsign: *const c_char,
+        grade: *const c_char,
+        DL: *mut __BindgenComplex<f64>,
+        model: *const lapack_int,
+        condl: *const f64,
+        DR: *mut __BindgenComplex<f64>,
+        moder: *const lapack_int,
+        condr: *const f64,
+        pivtng: *const c_char,
+        ipivot: *const lapack_int,
+        kl: *const lapack_int,
+        ku: *const lapack_int,
+        sparse: *const f64,
+        anorm: *const f64,
+        pack: *const c_char,
+        A: *mut __BindgenComplex<f64>,
+        lda: *const lapack_int,
+        iwork: *mut lapack_int,
+        info: *mut lapack_int,
+    );
+}